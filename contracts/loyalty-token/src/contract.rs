@@ -0,0 +1,41 @@
+use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use stellar_access::ownable::{self, Ownable};
+use stellar_macros::{default_impl, only_owner};
+use stellar_tokens::fungible::{burnable::FungibleBurnable, Base, FungibleToken};
+
+#[contract]
+pub struct LoyaltyToken;
+
+#[contractimpl]
+impl LoyaltyToken {
+    // el owner es el contrato de rent-a-car, el unico que puede mintear puntos
+    pub fn __constructor(e: &Env, owner: Address) {
+        Base::set_metadata(
+            e,
+            0,
+            String::from_str(e, "Odisea Loyalty Points"),
+            String::from_str(e, "ODPTS"),
+        );
+        ownable::set_owner(e, &owner);
+    }
+
+    #[only_owner]
+    pub fn mint(e: &Env, to: Address, amount: i128) {
+        Base::mint(e, &to, amount);
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleToken for LoyaltyToken {
+    type ContractType = Base;
+}
+
+// el renter quema sus puntos cuando los usa como descuento en una renta
+#[default_impl]
+#[contractimpl]
+impl FungibleBurnable for LoyaltyToken {}
+
+#[default_impl]
+#[contractimpl]
+impl Ownable for LoyaltyToken {}
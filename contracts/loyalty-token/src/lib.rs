@@ -0,0 +1,6 @@
+#![no_std]
+
+mod contract;
+mod tests;
+
+pub use contract::{LoyaltyToken, LoyaltyTokenClient};
\ No newline at end of file
@@ -0,0 +1,48 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address, Env, IntoVal};
+use crate::{LoyaltyToken, LoyaltyTokenClient};
+
+fn setup<'a>(env: &Env, owner: &Address) -> LoyaltyTokenClient<'a> {
+    let contract_id = env.register(LoyaltyToken, (owner,));
+    LoyaltyTokenClient::new(env, &contract_id)
+}
+
+#[test]
+pub fn test_owner_mints_and_holder_burns_points() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let token = setup(&env, &owner);
+
+    token.mint(&renter, &450_i128);
+    assert_eq!(token.balance(&renter), 450);
+    assert_eq!(token.total_supply(), 450);
+
+    token.burn(&renter, &100_i128);
+    assert_eq!(token.balance(&renter), 350);
+    assert_eq!(token.total_supply(), 350);
+    assert_eq!(token.decimals(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_mint_without_owner_auth_fails() {
+    let env = Env::default();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let token = setup(&env, &owner);
+
+    token
+        .mock_auths(&[MockAuth {
+            address: &renter,
+            invoke: &MockAuthInvoke {
+                contract: &token.address,
+                fn_name: "mint",
+                args: (renter.clone(), 450_i128).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .mint(&renter, &450_i128);
+}
\ No newline at end of file
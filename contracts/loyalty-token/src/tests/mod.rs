@@ -0,0 +1,3 @@
+#![cfg(test)]
+
+pub mod loyalty_token;
\ No newline at end of file
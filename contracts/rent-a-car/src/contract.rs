@@ -1,16 +1,106 @@
-use soroban_sdk::{Address, Env, contract, contractimpl};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec, contract, contractimpl};
+use stellar_access::access_control;
+use stellar_macros::default_impl;
+use stellar_tokens::non_fungible::{Base, NonFungibleToken};
 
 use crate::interfaces::contract::RentACarContractTrait;
 use crate::storage::{
-    admin::{read_admin, write_admin, has_admin, write_commission, read_commission},
-    car::{read_car, write_car, remove_car, has_car},
-    rental::write_rental,
-    structs::{car::Car, rental::Rental},
-    token::write_token,
-    types::{car_status::CarStatus, errors::Error},
-    contract_balance::{read_contract_balance, write_contract_balance}
+    admin::{
+        read_admin, write_admin, has_admin, write_commission, read_commission,
+        read_treasury, write_treasury, read_commission_recipient,
+    },
+    car::{
+        read_car, write_car, remove_car, has_car, remove_car_token, read_holder_cars, read_holder_credit,
+        write_holder_credit,
+    },
+    device::{read_car_device, write_car_device, remove_car_device},
+    condition::{has_condition_report, read_condition_report, write_condition_report},
+    category::{
+        read_category_rules, write_category_rules, read_category_cars,
+        read_renter_verification, write_renter_verification,
+    },
+    co_owner::{
+        has_co_owners, read_co_owners, write_co_owners, remove_co_owners,
+        read_co_owner_balance, write_co_owner_balance,
+    },
+    coupon::{has_coupon, read_coupon, write_coupon},
+    pricing::{
+        read_pricing_rules, write_pricing_rules, remove_pricing_rules,
+        read_discount_tiers, write_discount_tiers, remove_discount_tiers,
+    },
+    referral::{
+        has_referrer, read_referrer, write_referrer, read_referral_share, write_referral_share,
+        read_referral_balance, write_referral_balance,
+    },
+    insurance::{
+        read_insurance_config, write_insurance_config, read_insurance_pool,
+        write_insurance_pool, has_claim, read_claim, write_claim, read_insured_rental,
+        write_insured_rental,
+    },
+    loyalty::{read_loyalty_config, write_loyalty_config},
+    rental::{read_rental, remove_rental, write_rental, next_rental_id},
+    structs::{
+        car::Car,
+        category::CategoryRules,
+        condition::ConditionReport,
+        driver::RentalDriver,
+        co_owner::CoOwner,
+        coupon::Coupon,
+        insurance::{InsuranceClaim, InsuranceConfig, InsuredRental},
+        loyalty::LoyaltyConfig,
+        pricing::{DiscountTier, PricingRules, SeasonalRate},
+        rental::Rental,
+        usage::{UsageFees, UsageReading},
+        withdrawal::{WithdrawalPolicy, WithdrawalProposal},
+        zone::{CarLocation, Zone},
+    },
+    token::{read_token, write_token},
+    types::{
+        car_category::CarCategory, car_status::CarStatus, claim_status::ClaimStatus, coupon_discount::CouponDiscount,
+        device_action::DeviceAction, errors::Error, reading_kind::ReadingKind, role::Role,
+        withdrawal_status::WithdrawalStatus,
+    },
+    contract_balance::{read_contract_balance, write_contract_balance},
+    usage::{
+        read_usage_debt, read_usage_fees, write_usage_debt, write_usage_fees, remove_usage_fees,
+        read_renter_usage_debt, write_renter_usage_debt,
+    },
+    zone::{has_zone, read_zone, write_zone, remove_zone, read_zone_cars},
+    withdrawal::{
+        next_withdrawal_id, read_withdrawal,
+        read_withdrawal_policy, write_withdrawal, write_withdrawal_policy, read_withdrawal_window,
+        write_withdrawal_window,
+    },
 };
-use crate::methods::token::token::token_transfer;
+use crate::methods::{
+    co_owner::co_owner::{split_owner_credit, validate_co_owners},
+    category::category::{
+        add_to_category_index, enforce_category_rules, remove_from_category_index, validate_category_rules,
+    },
+    condition::condition::validate_evidence,
+    device::device::verify_device_report,
+    pickup::pickup::{release_driver_fees, start_rental},
+    coupon::coupon::redeem_coupon,
+    insurance::insurance::compute_insurance_premium,
+    listing::listing::paginate,
+    loyalty::loyalty::{mint_loyalty_points, redeem_loyalty_points},
+    nft::nft::{
+        mint_car_token, on_car_token_transfer, read_car_holder, release_held_holder_credit, remove_from_holder_index,
+        require_car_holder,
+    },
+    referral::referral::accrue_referral_reward,
+    payout::payout::sweep_owner_balance,
+    pricing::pricing::{
+        compute_long_term_discount, compute_rental_price, validate_discount_tiers, validate_pricing_rules,
+    },
+    roles::roles::{self, require_admin, require_role},
+    token::token::token_transfer,
+    usage::usage::{compute_usage_charge, validate_reading, validate_usage_fees},
+    zone::zone::{add_to_zone_index, remove_from_zone_index, validate_car_location, validate_zone},
+};
+
+// tope de conductores adicionales por renta
+const MAX_ADDITIONAL_DRIVERS: u32 = 3;
 
 #[contract]
 pub struct RentACarContract;
@@ -27,7 +117,14 @@ impl RentACarContractTrait for RentACarContract {
         }
 
         write_admin(env, &admin);
+        access_control::set_admin(env, &admin);
         write_token(env, &token);
+        Base::set_metadata(
+            env,
+            String::from_str(env, "https://odisea.rent/cars/"),
+            String::from_str(env, "Odisea Rent a Car"),
+            String::from_str(env, "ODCAR"),
+        );
         crate::events::contract::contract_initialized(env, admin, token);
 
         Ok(())
@@ -38,13 +135,48 @@ impl RentACarContractTrait for RentACarContract {
     //     env.storage().instance().set(TOKEN_KEY, &token);
     // }
 
-    fn get_admin(env: &Env) -> Address {
+    fn get_admin(env: &Env) -> Result<Address, Error> {
         read_admin(env)
     }
 
-    fn add_car(env: &Env, owner: Address, price_per_day: i128) -> Result<(), Error> {
-        let admin = read_admin(env);
-        admin.require_auth();
+    fn grant_role(env: &Env, caller: Address, account: Address, role: Role) -> Result<(), Error> {
+        require_admin(env, &caller)?;
+
+        // el admin no se delega, los roles otorgables son los de operacion
+        if role == Role::Admin {
+            return Err(Error::AdminRoleNotGrantable);
+        }
+
+        // access_control de OpenZeppelin emite el evento role_granted
+        access_control::grant_role_no_auth(env, &caller, &account, &role.to_symbol(env));
+        Ok(())
+    }
+
+    fn revoke_role(env: &Env, caller: Address, account: Address, role: Role) -> Result<(), Error> {
+        require_admin(env, &caller)?;
+
+        if !roles::has_role(env, &account, &role) {
+            return Err(Error::RoleNotGranted);
+        }
+
+        // access_control de OpenZeppelin emite el evento role_revoked
+        access_control::revoke_role_no_auth(env, &caller, &account, &role.to_symbol(env));
+        Ok(())
+    }
+
+    fn has_role(env: &Env, account: Address, role: Role) -> bool {
+        roles::has_role(env, &account, &role)
+    }
+
+    fn add_car(
+        env: &Env,
+        caller: Address,
+        owner: Address,
+        price_per_day: i128,
+        category: CarCategory,
+        location: Option<CarLocation>,
+    ) -> Result<(), Error> {
+        require_role(env, &caller, &[Role::Admin, Role::FleetManager])?;
         if price_per_day <= 0 {
             return Err(Error::AmountMustBePositive);
         }
@@ -53,48 +185,401 @@ impl RentACarContractTrait for RentACarContract {
             return Err(Error::CarAlreadyExist);
         }
 
+        // la ubicacion es opcional, se puede asignar despues con set_car_location
+        if let Some(location) = &location {
+            validate_car_location(env, location.zone_id, &location.pickup_point)?;
+        }
+
+        let token_id = mint_car_token(env, &owner);
+
         let car = Car {
             price_per_day,
             car_status: CarStatus::Available,
             available_to_withdraw: 0,
+            pending_to_withdraw: 0,
+            pickup_code_required: false,
+            driver_fee_per_day: 0,
+            min_days: 0,
+            max_days: 0,
+            min_notice_secs: 0,
+            payout_address: owner.clone(),
+            token_id,
+            location: location.clone(),
+            category: category.clone(),
         };
 
         write_car(env, &owner, &car);
+        add_to_category_index(env, &category, &owner);
+
+        if let Some(location) = location {
+            add_to_zone_index(env, location.zone_id, &owner);
+            crate::events::zone::car_location_changed(env, owner.clone(), token_id, location.zone_id, location.pickup_point);
+        }
 
-        crate::events::add_car::car_added(env, owner, price_per_day);
+        crate::events::add_car::car_added(env, owner, token_id, price_per_day);
         Ok(())
     }
 
     fn get_car_status(env: &Env, owner: Address) -> Result<CarStatus, Error> {
+        let car = read_car(env, &owner)?;
+
+        Ok(car.car_status)
+    }
+
+    fn get_available_withdraw_payowner(env: &Env, owner: Address) -> Result<i128, Error> {
+        let car = read_car(env, &owner)?;
+        Ok(car.available_to_withdraw)
+    }
+
+    fn get_admin_commission(env: &Env) -> Result<i128, Error> {
+        if !has_admin(env) {
+            return Err(Error::ContractNotInitialized);
+        }
+
+        Ok(read_commission(env))
+    }
+
+    fn set_pricing_rules(
+        env: &Env,
+        owner: Address,
+        weekday_multipliers_bps: Vec<u32>,
+        seasonal_rates: Vec<SeasonalRate>,
+    ) -> Result<(), Error> {
+        let car = read_car(env, &owner)?;
+        require_car_holder(env, &car);
+
+        let rules = PricingRules {
+            weekday_multipliers_bps,
+            seasonal_rates,
+        };
+
+        validate_pricing_rules(&rules)?;
+        write_pricing_rules(env, &owner, &rules);
+
+        crate::events::pricing::pricing_rules_updated(env, owner, car.token_id, rules);
+        Ok(())
+    }
+
+    fn get_pricing_rules(env: &Env, owner: Address) -> Result<PricingRules, Error> {
         if !has_car(env, &owner) {
             return Err(Error::CarNotFound);
         }
 
-        let car = read_car(env, &owner);
+        Ok(read_pricing_rules(env, &owner).unwrap_or(PricingRules {
+            weekday_multipliers_bps: Vec::new(env),
+            seasonal_rates: Vec::new(env),
+        }))
+    }
 
-        Ok(car.car_status)
+    fn set_discount_tiers(env: &Env, owner: Address, tiers: Vec<DiscountTier>) -> Result<(), Error> {
+        let car = read_car(env, &owner)?;
+        require_car_holder(env, &car);
+
+        validate_discount_tiers(&tiers)?;
+        write_discount_tiers(env, &owner, &tiers);
+
+        crate::events::pricing::discount_tiers_updated(env, owner, car.token_id, tiers);
+        Ok(())
     }
 
-    fn get_available_withdraw_payowner(env: &Env, owner: Address) -> Result<i128, Error> {
+    fn get_discount_tiers(env: &Env, owner: Address) -> Result<Vec<DiscountTier>, Error> {
         if !has_car(env, &owner) {
             return Err(Error::CarNotFound);
         }
 
-        let car = read_car(env, &owner);
-        Ok(car.available_to_withdraw)
+        Ok(read_discount_tiers(env, &owner))
+    }
+
+    fn quote_rental(env: &Env, owner: Address, total_days_to_rent: u32, start_at: u64) -> Result<i128, Error> {
+        if total_days_to_rent == 0 {
+            return Err(Error::RentalDurationCannotBeZero);
+        }
+
+        let car = read_car(env, &owner)?;
+        let gross_price = compute_rental_price(env, &owner, &car, total_days_to_rent, start_at);
+        let price = gross_price - compute_long_term_discount(env, &owner, total_days_to_rent, gross_price);
+
+        // la prima se cobra encima del precio, el quote es lo que el renter tiene que enviar sin deposito
+        Ok(price + compute_insurance_premium(env, price))
+    }
+
+    fn create_coupon(
+        env: &Env,
+        caller: Address,
+        code_hash: BytesN<32>,
+        discount: CouponDiscount,
+        max_uses: u32,
+        per_address_limit: u32,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        require_admin(env, &caller)?;
+
+        let valid_discount = match discount {
+            CouponDiscount::Fixed(value) => value > 0,
+            CouponDiscount::Bps(bps) => bps > 0 && bps <= 10_000,
+        };
+
+        if !valid_discount || max_uses == 0 || per_address_limit == 0 || expires_at <= env.ledger().timestamp() {
+            return Err(Error::InvalidCoupon);
+        }
+
+        if has_coupon(env, &code_hash) {
+            return Err(Error::CouponAlreadyExists);
+        }
+
+        let coupon = Coupon {
+            discount,
+            uses_remaining: max_uses,
+            per_address_limit,
+            expires_at,
+        };
+
+        write_coupon(env, &code_hash, &coupon);
+
+        crate::events::coupon::coupon_created(env, code_hash, coupon);
+        Ok(())
     }
 
-    fn get_admin_commission(env: &Env) -> i128 {
-        read_commission(env)
+    fn get_coupon(env: &Env, code_hash: BytesN<32>) -> Result<Coupon, Error> {
+        read_coupon(env, &code_hash)
     }
 
-    fn rental(env: &Env, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128) -> Result<(), Error>{
+    fn register_referrer(env: &Env, renter: Address, referrer: Address) -> Result<(), Error> {
         renter.require_auth();
 
+        if renter == referrer {
+            return Err(Error::SelfReferralNotAllowed);
+        }
+
+        // el referidor se registra una sola vez por renter
+        if has_referrer(env, &renter) {
+            return Err(Error::ReferrerAlreadySet);
+        }
+
+        write_referrer(env, &renter, &referrer);
+
+        crate::events::referral::referrer_registered(env, renter, referrer);
+        Ok(())
+    }
+
+    fn get_referrer(env: &Env, renter: Address) -> Result<Address, Error> {
+        read_referrer(env, &renter)
+    }
+
+    fn set_referral_share(env: &Env, caller: Address, share_bps: u32) -> Result<(), Error> {
+        require_admin(env, &caller)?;
+
+        if share_bps > 10_000 {
+            return Err(Error::InvalidReferralShare);
+        }
+
+        write_referral_share(env, share_bps);
+        Ok(())
+    }
+
+    fn get_referral_share(env: &Env) -> u32 {
+        read_referral_share(env)
+    }
+
+    fn get_referral_balance(env: &Env, referrer: Address) -> i128 {
+        read_referral_balance(env, &referrer)
+    }
+
+    fn claim_referral_rewards(env: &Env, referrer: Address) -> Result<i128, Error> {
+        referrer.require_auth();
+
+        let balance = read_referral_balance(env, &referrer);
+
+        if balance <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+
+        write_referral_balance(env, &referrer, 0);
+
+        token_transfer(env, &env.current_contract_address(), &referrer, &balance)?;
+        crate::events::referral::referral_rewards_claimed(env, referrer, balance);
+        Ok(balance)
+    }
+
+    fn set_loyalty_config(
+        env: &Env,
+        caller: Address,
+        token: Address,
+        earn_rate_bps: u32,
+        redeem_rate_bps: u32,
+    ) -> Result<(), Error> {
+        require_admin(env, &caller)?;
+
+        // el token de lealtad no puede ser el mismo token con el que se paga
+        if token == read_token(env)? || earn_rate_bps == 0 || redeem_rate_bps == 0 {
+            return Err(Error::InvalidLoyaltyConfig);
+        }
+
+        let config = LoyaltyConfig {
+            token,
+            earn_rate_bps,
+            redeem_rate_bps,
+        };
+
+        write_loyalty_config(env, &config);
+        Ok(())
+    }
+
+    fn get_loyalty_config(env: &Env) -> Result<LoyaltyConfig, Error> {
+        read_loyalty_config(env)
+    }
+
+    fn set_insurance_config(
+        env: &Env,
+        caller: Address,
+        premium_bps: u32,
+        max_claim_amount: i128,
+        claim_window_secs: u64,
+    ) -> Result<(), Error> {
+        require_admin(env, &caller)?;
+
+        if premium_bps == 0 || premium_bps >= 10_000 || max_claim_amount <= 0 {
+            return Err(Error::InvalidInsuranceConfig);
+        }
+
+        let config = InsuranceConfig {
+            premium_bps,
+            max_claim_amount,
+            claim_window_secs,
+        };
+
+        write_insurance_config(env, &config);
+        Ok(())
+    }
+
+    fn get_insurance_config(env: &Env) -> Result<InsuranceConfig, Error> {
+        read_insurance_config(env)
+    }
+
+    fn get_insurance_pool(env: &Env) -> i128 {
+        read_insurance_pool(env)
+    }
+
+    fn file_insurance_claim(env: &Env, owner: Address, rental_id: u32, amount: i128) -> Result<(), Error> {
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        let config = read_insurance_config(env)?;
+
+        // solo las rentas que pagaron prima sobre este carro tienen cobertura. el reclamo se resuelve
+        // con el registro del seguro y no con el carro, asi sigue valiendo despues de remove_car
+        let insured = read_insured_rental(env, rental_id)?;
+
+        if insured.owner != owner {
+            return Err(Error::RentalNotFound);
+        }
+
+        let claimant = insured.holder.clone();
+        claimant.require_auth();
+
+        if has_claim(env, rental_id) {
+            return Err(Error::ClaimAlreadyFiled);
+        }
+
+        // durante la renta se puede reclamar siempre, despues solo dentro de la ventana
+        if let Some(returned_at) = insured.returned_at {
+            if env.ledger().timestamp() > returned_at + config.claim_window_secs {
+                return Err(Error::ClaimWindowClosed);
+            }
+        }
+
+        if amount > config.max_claim_amount {
+            return Err(Error::ClaimExceedsCap);
+        }
+
+        let claim = InsuranceClaim {
+            rental_id,
+            owner: owner.clone(),
+            renter: insured.renter.clone(),
+            claimant,
+            amount,
+            paid: 0,
+            status: ClaimStatus::Pending,
+        };
+
+        write_claim(env, rental_id, &claim);
+
+        crate::events::insurance::claim_filed(env, owner, insured.renter, rental_id, amount);
+        Ok(())
+    }
+
+    fn approve_insurance_claim(env: &Env, caller: Address, rental_id: u32, amount: i128) -> Result<(), Error> {
+        require_role(env, &caller, &[Role::Admin, Role::Arbiter])?;
+
         if amount <= 0 {
             return Err(Error::AmountMustBePositive);
         }
 
+        let mut claim = read_claim(env, rental_id)?;
+
+        if claim.status != ClaimStatus::Pending {
+            return Err(Error::ClaimNotPending);
+        }
+
+        // se puede aprobar menos de lo pedido, nunca mas ni por encima del tope
+        if amount > claim.amount || amount > read_insurance_config(env)?.max_claim_amount {
+            return Err(Error::ClaimExceedsCap);
+        }
+
+        let pool = read_insurance_pool(env);
+
+        if amount > pool {
+            return Err(Error::InsufficientInsurancePool);
+        }
+
+        claim.status = ClaimStatus::Approved;
+        claim.paid = amount;
+
+        write_claim(env, rental_id, &claim);
+        write_insurance_pool(env, pool - amount);
+
+        token_transfer(env, &env.current_contract_address(), &claim.claimant, &amount)?;
+        crate::events::insurance::claim_approved(env, rental_id, caller, amount);
+        Ok(())
+    }
+
+    fn reject_insurance_claim(env: &Env, caller: Address, rental_id: u32) -> Result<(), Error> {
+        require_role(env, &caller, &[Role::Admin, Role::Arbiter])?;
+
+        let mut claim = read_claim(env, rental_id)?;
+
+        if claim.status != ClaimStatus::Pending {
+            return Err(Error::ClaimNotPending);
+        }
+
+        claim.status = ClaimStatus::Rejected;
+        write_claim(env, rental_id, &claim);
+
+        crate::events::insurance::claim_rejected(env, rental_id, caller);
+        Ok(())
+    }
+
+    fn get_insurance_claim(env: &Env, rental_id: u32) -> Result<InsuranceClaim, Error> {
+        read_claim(env, rental_id)
+    }
+
+    fn rental(
+        env: &Env,
+        renter: Address,
+        owner: Address,
+        total_days_to_rent: u32,
+        amount: i128,
+        promo_code: Option<Bytes>,
+        loyalty_points: i128,
+        start_at: u64,
+    ) -> Result<(), Error> {
+        renter.require_auth();
+
+        if amount <= 0 || loyalty_points < 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
         if total_days_to_rent == 0 {
             return Err(Error::RentalDurationCannotBeZero);
         }
@@ -103,113 +588,1282 @@ impl RentACarContractTrait for RentACarContract {
             return Err(Error::SelfRentalNotAllowed);
         }
 
-        if !has_car(env, &owner) {
-            return Err(Error::CarNotFound);
-        }
+        let mut car = read_car(env, &owner)?;
 
-        let mut car = read_car(env, &owner);
+        if renter == read_car_holder(env, &car) {
+            return Err(Error::SelfRentalNotAllowed);
+        }
 
         if car.car_status != CarStatus::Available {
             return Err(Error::CarAlreadyRented);
         }
 
+        // con cargos por uso sin pagar en cualquier carro no se renta ninguno
+        if read_renter_usage_debt(env, &renter) > 0 {
+            return Err(Error::OutstandingUsageDebt);
+        }
+
+        if total_days_to_rent < car.min_days {
+            return Err(Error::RentalBelowMinDays);
+        }
+
+        if car.max_days > 0 && total_days_to_rent > car.max_days {
+            return Err(Error::RentalAboveMaxDays);
+        }
+
+        // un start_at en el pasado cuenta como cero anticipacion
+        if start_at.saturating_sub(env.ledger().timestamp()) < car.min_notice_secs {
+            return Err(Error::InsufficientNotice);
+        }
+
+        // el deposito de la categoria se cobra aparte y se devuelve al completar la renta
+        let deposit = enforce_category_rules(env, &renter, &car.category, total_days_to_rent)?;
+
+        // `amount` es lo maximo que el renter autoriza, se cobra el precio calculado
+        let gross_price = compute_rental_price(env, &owner, &car, total_days_to_rent, start_at);
+        let discount = compute_long_term_discount(env, &owner, total_days_to_rent, gross_price);
+        let price = gross_price - discount;
+
+        // el descuento del cupon lo paga la comision del admin, no el owner
+        let coupon_discount = match promo_code {
+            Some(code) => redeem_coupon(env, &renter, &code, price)?,
+            None => 0,
+        };
+
+        // los puntos quemados tambien se descuentan de la comision
+        let loyalty_discount = if loyalty_points > 0 {
+            redeem_loyalty_points(env, &renter, loyalty_points, price - coupon_discount)?
+        } else {
+            0
+        };
+
+        let subsidy = coupon_discount + loyalty_discount;
+
+        // la prima del seguro se cobra encima del precio y va directo al pool
+        let insurance_premium = compute_insurance_premium(env, price);
+
+        if amount < price - subsidy + insurance_premium + deposit {
+            return Err(Error::AmountBelowRentalPrice);
+        }
+
+        let amount = price - subsidy + insurance_premium + deposit;
+        let commission = (price / 100) * 2; // 2% de comisión por cada rental
+
+        // al owner se le acredita lo mismo que entra al ContractBalance, la comision
+        // queda en su propio ledger y nunca se puede retirar como credito del owner
+        let owner_credit = price - commission;
+
+        // el carro queda Rented desde la reserva y no desde start_at: no hay calendario de reservas,
+        // asi que una reserva anticipada bloquea el carro hasta complete_rental
         car.car_status = CarStatus::Rented;
-        car.available_to_withdraw += amount;
+
+        // con codigo de recogida el credito queda pendiente hasta confirm_pickup,
+        // si el carro tiene co-owners se reparte entre sus balances
+        let pending_credit = if car.pickup_code_required {
+            car.pending_to_withdraw += owner_credit;
+            owner_credit
+        } else if has_co_owners(env, &owner) {
+            split_owner_credit(env, &owner, owner_credit);
+            0
+        } else {
+            car.available_to_withdraw += owner_credit;
+            0
+        };
 
         let rental = Rental {
+            id: next_rental_id(env),
             total_days_to_rent,
+            start_at,
             amount,
+            discount,
+            coupon_discount,
+            loyalty_discount,
+            insurance_premium,
+            deposit,
+            pickup_reading: None,
+            return_reading: None,
+            started_at: None,
+            ended_at: None,
+            pickup_code_hash: None,
+            pending_credit,
+            drivers: Vec::new(env),
         };
 
-        let commission = (amount / 100) * 2; // 2% de comisión por cada rental
+        // la parte del referidor sale de la comision de esta renta
+        let referral_reward = accrue_referral_reward(env, &renter, commission);
 
         let mut contract_balance = read_contract_balance(&env);
         let mut admin_commission = read_commission(&env);
 
-        contract_balance += amount - commission;
-        admin_commission += commission;
+        if admin_commission + commission - referral_reward < subsidy {
+            return Err(Error::CouponSubsidyExceedsCommission);
+        }
+
+        let previous_balance = contract_balance;
+        let previous_commission = admin_commission;
+
+        contract_balance += owner_credit;
+        admin_commission += commission - referral_reward - subsidy;
         
         write_contract_balance(&env, &contract_balance);
         write_commission(&env, admin_commission);
         write_car(env, &owner, &car);
         write_rental(env, &renter, &owner, &rental);
 
-        token_transfer(&env, &renter, &env.current_contract_address(), &amount);
-        crate::events::rental::rented(env, renter, owner, total_days_to_rent, amount);
+        if insurance_premium > 0 {
+            let insured = InsuredRental {
+                owner: owner.clone(),
+                renter: renter.clone(),
+                holder: read_car_holder(env, &car),
+                returned_at: None,
+            };
+
+            write_insured_rental(env, rental.id, &insured);
+            write_insurance_pool(env, read_insurance_pool(env) + insurance_premium);
+            crate::events::insurance::premium_collected(env, renter.clone(), owner.clone(), rental.id, insurance_premium);
+        }
+
+        token_transfer(&env, &renter, &env.current_contract_address(), &amount)?;
+        crate::events::commission::commission_accrued(env, rental.id, commission, admin_commission);
+        crate::events::balance::contract_balance_changed(env, previous_balance, contract_balance);
+        crate::events::balance::admin_commission_changed(env, previous_commission, admin_commission);
+        crate::events::rental::rented(env, renter, owner, car.token_id, rental.id, total_days_to_rent, amount, discount);
         Ok(())
     }
 
-    fn payout_owner(env: &Env, owner: Address, amount: i128) -> Result<(), Error> {
-        owner.require_auth();
+    fn complete_rental(env: &Env, owner: Address, renter: Address) -> Result<(), Error> {
+        let mut car = read_car(env, &owner)?;
+        require_car_holder(env, &car);
 
-        if amount <= 0 {
-            return Err(Error::AmountMustBePositive);
-        }
+        let rental = read_rental(env, &renter, &owner)?;
 
-        if !has_car(env, &owner) {
-            return Err(Error::CarNotFound);
+        if car.car_status != CarStatus::Rented {
+            return Err(Error::CarNotRented);
         }
 
-        let mut car = read_car(&env, &owner);
+        // con tarifas por uso y entrega confirmada, la devolucion tiene que estar confirmada,
+        // si el renter no la envia el owner la registra con record_return_reading
+        let pickup_confirmed = rental.pickup_reading.as_ref().is_some_and(|r| r.confirmed);
+        let return_confirmed = rental.return_reading.as_ref().is_some_and(|r| r.confirmed);
 
-        // valida que el usuario no pueda hacer payout si el carro sigue rentado
-        if car.car_status == CarStatus::Rented {
-            return Err(Error::CannotPayoutWhileCarIsRented)
+        if read_usage_fees(env, &owner).is_ok() && pickup_confirmed && !return_confirmed {
+            return Err(Error::ReturnReadingNotConfirmed);
         }
 
-        if amount > car.available_to_withdraw {
-            return Err(Error::InsufficientBalance);
-        }
+        // los km extra y el combustible faltante se cobran primero contra el deposito,
+        // lo que no alcance queda como deuda del renter, el owner no puede cobrarle sin su firma
+        let (extra_km, km_charge, fuel_charge) = compute_usage_charge(env, &owner, &rental);
+        let usage_charge = km_charge + fuel_charge;
+        let from_deposit = usage_charge.min(rental.deposit);
+        let owed = usage_charge - from_deposit;
 
+        car.car_status = CarStatus::Available;
 
-        let mut contract_balance = read_contract_balance(&env);
+        // si el renter nunca recogio el carro se le devuelve lo que pago por la renta menos la comision,
+        // junto con todos los cargos de conductores que seguian retenidos. el subsidio del cupon o de los
+        // puntos no lo pago el renter, vuelve a la comision que lo absorbio
+        let no_show = rental.started_at.is_none() && rental.pending_credit > 0;
+        let no_show_refund = (rental.pending_credit - rental.coupon_discount - rental.loyalty_discount).max(0);
+        let returned_subsidy = rental.pending_credit - no_show_refund;
+        let accepted_driver_fees: i128 = rental.drivers.iter().filter(|d| d.accepted).map(|d| d.fee).sum();
+        let unaccepted_driver_fees: i128 = rental.drivers.iter().filter(|d| !d.accepted).map(|d| d.fee).sum();
+        car.pending_to_withdraw -= rental.pending_credit;
 
-        if amount > contract_balance {
-            return Err(Error::BalanceNotAvailableForAmountRequested);
+        // los que nunca aceptaron se devuelven siempre, los aceptados solo en un no-show.
+        // una renta que termina sin pasar por la recogida libera ahora los cargos aceptados
+        let driver_fees_refund = if no_show {
+            unaccepted_driver_fees + accepted_driver_fees
+        } else {
+            unaccepted_driver_fees
+        };
+
+        if rental.started_at.is_none() && !no_show {
+            release_driver_fees(env, &owner, &mut car, accepted_driver_fees);
         }
 
-        car.available_to_withdraw -= amount;
-        contract_balance -= amount;
+        if from_deposit > 0 {
+            if has_co_owners(env, &owner) {
+                split_owner_credit(env, &owner, from_deposit);
+            } else {
+                car.available_to_withdraw += from_deposit;
+            }
+        }
 
-        write_car(&env, &owner, &car);
-        write_contract_balance(&env, &contract_balance);
+        write_car(env, &owner, &car);
+        remove_rental(env, &renter, &owner);
+        release_held_holder_credit(env, &owner, car.token_id);
 
-        token_transfer(&env, &env.current_contract_address(), &owner, &amount);
-        crate::events::payout_owner::payout(env, owner, amount);
-        Ok(())
-    }
+        // desde la devolucion corre la ventana para reclamar al seguro
+        if let Ok(mut insured) = read_insured_rental(env, rental.id) {
+            insured.returned_at = Some(env.ledger().timestamp());
+            write_insured_rental(env, rental.id, &insured);
+        }
 
-    fn payout_admin(env: &Env, admin: Address, amount: i128) -> Result<(), Error> {
-        admin.require_auth();
+        if owed > 0 {
+            write_usage_debt(env, &renter, &owner, read_usage_debt(env, &renter, &owner) + owed);
+            write_renter_usage_debt(env, &renter, read_renter_usage_debt(env, &renter) + owed);
+        }
 
-        if amount <= 0 {
-            return Err(Error::AmountMustBePositive);
+        if usage_charge > 0 {
+            let previous_balance = read_contract_balance(env);
+            write_contract_balance(env, &(previous_balance + from_deposit));
+
+            crate::events::balance::contract_balance_changed(env, previous_balance, previous_balance + from_deposit);
+            crate::events::usage::usage_charged(env, renter.clone(), rental.id, extra_km, km_charge, fuel_charge, from_deposit);
         }
 
-        let mut available_commision = read_commission(&env);
+        if rental.deposit > from_deposit {
+            token_transfer(&env, &env.current_contract_address(), &renter, &(rental.deposit - from_deposit))?;
+        }
 
-        if amount > read_commission(&env) {
-            return Err(Error::InsufficientBalance);
+        if driver_fees_refund > 0 {
+            token_transfer(&env, &env.current_contract_address(), &renter, &driver_fees_refund)?;
         }
 
-        available_commision -= amount;
+        if rental.pending_credit > 0 {
+            let previous_balance = read_contract_balance(env);
+            write_contract_balance(env, &(previous_balance - rental.pending_credit));
 
-        write_commission(&env, available_commision);
+            if returned_subsidy > 0 {
+                let previous_commission = read_commission(env);
+                write_commission(env, previous_commission + returned_subsidy);
+                crate::events::balance::admin_commission_changed(env, previous_commission, previous_commission + returned_subsidy);
+            }
+
+            if no_show_refund > 0 {
+                token_transfer(&env, &env.current_contract_address(), &renter, &no_show_refund)?;
+            }
+
+            crate::events::balance::contract_balance_changed(env, previous_balance, previous_balance - rental.pending_credit);
+        }
 
-        token_transfer(&env, &env.current_contract_address(), &admin, &amount);
-        crate::events::payout_admin::payout(env, admin, amount);
+        // los puntos se calculan sobre lo que el renter pago por la renta, sin deposito, prima ni reembolsos
+        let points = mint_loyalty_points(
+            env,
+            &renter,
+            rental.amount - rental.deposit - rental.insurance_premium - no_show_refund,
+        );
+
+        crate::events::rental::rental_completed(env, renter, owner, car.token_id, rental.id, points);
         Ok(())
     }
 
-    fn remove_car(env: &Env, owner: Address) -> Result<(), Error> {
-        let admin = read_admin(env);
-        admin.require_auth();
-        if !has_car(env, &owner) {
-            return Err(Error::CarNotFound);
-        }
+    fn set_usage_fees(
+        env: &Env,
+        owner: Address,
+        included_km_per_day: u32,
+        price_per_extra_km: i128,
+        refuel_fee_per_pct: i128,
+    ) -> Result<(), Error> {
+        let car = read_car(env, &owner)?;
+        require_car_holder(env, &car);
 
-        remove_car(env, &owner);
-        crate::events::remove_car::car_removed(env, owner);
+        let fees = UsageFees {
+            included_km_per_day,
+            price_per_extra_km,
+            refuel_fee_per_pct,
+        };
+
+        validate_usage_fees(&fees)?;
+        write_usage_fees(env, &owner, &fees);
+
+        crate::events::usage::usage_fees_updated(env, owner, car.token_id, fees);
         Ok(())
     }
-}
\ No newline at end of file
+
+    fn get_usage_fees(env: &Env, owner: Address) -> Result<UsageFees, Error> {
+        read_car(env, &owner)?;
+
+        read_usage_fees(env, &owner)
+    }
+
+    fn submit_usage_reading(
+        env: &Env,
+        renter: Address,
+        owner: Address,
+        kind: ReadingKind,
+        odometer_km: u32,
+        fuel_level_pct: u32,
+    ) -> Result<(), Error> {
+        renter.require_auth();
+
+        let mut rental = read_rental(env, &renter, &owner)?;
+
+        let reading = UsageReading {
+            odometer_km,
+            fuel_level_pct,
+            confirmed: false,
+        };
+
+        // mientras el owner no confirme, el renter puede corregir la lectura
+        match kind {
+            ReadingKind::Pickup => {
+                if rental.pickup_reading.as_ref().is_some_and(|r| r.confirmed) {
+                    return Err(Error::ReadingAlreadyConfirmed);
+                }
+
+                validate_reading(&reading, &None)?;
+                rental.pickup_reading = Some(reading.clone());
+            }
+            ReadingKind::Return => {
+                if rental.return_reading.as_ref().is_some_and(|r| r.confirmed) {
+                    return Err(Error::ReadingAlreadyConfirmed);
+                }
+
+                // la devolucion se mide contra la entrega ya confirmada
+                if !rental.pickup_reading.as_ref().is_some_and(|r| r.confirmed) {
+                    return Err(Error::ReadingNotSubmitted);
+                }
+
+                validate_reading(&reading, &rental.pickup_reading)?;
+                rental.return_reading = Some(reading.clone());
+            }
+        }
+
+        write_rental(env, &renter, &owner, &rental);
+
+        crate::events::usage::usage_reading_recorded(env, rental.id, kind, reading);
+        Ok(())
+    }
+
+    fn confirm_usage_reading(env: &Env, owner: Address, renter: Address, kind: ReadingKind) -> Result<(), Error> {
+        let car = read_car(env, &owner)?;
+        require_car_holder(env, &car);
+
+        let mut rental = read_rental(env, &renter, &owner)?;
+
+        let slot = match kind {
+            ReadingKind::Pickup => &mut rental.pickup_reading,
+            ReadingKind::Return => &mut rental.return_reading,
+        };
+
+        let Some(reading) = slot.as_mut() else {
+            return Err(Error::ReadingNotSubmitted);
+        };
+
+        if reading.confirmed {
+            return Err(Error::ReadingAlreadyConfirmed);
+        }
+
+        reading.confirmed = true;
+        let reading = reading.clone();
+
+        write_rental(env, &renter, &owner, &rental);
+
+        crate::events::usage::usage_reading_recorded(env, rental.id, kind, reading);
+        Ok(())
+    }
+
+    fn record_return_reading(
+        env: &Env,
+        owner: Address,
+        renter: Address,
+        odometer_km: u32,
+        fuel_level_pct: u32,
+    ) -> Result<(), Error> {
+        let car = read_car(env, &owner)?;
+        require_car_holder(env, &car);
+
+        let mut rental = read_rental(env, &renter, &owner)?;
+
+        if rental.return_reading.as_ref().is_some_and(|r| r.confirmed) {
+            return Err(Error::ReadingAlreadyConfirmed);
+        }
+
+        if !rental.pickup_reading.as_ref().is_some_and(|r| r.confirmed) {
+            return Err(Error::ReadingNotSubmitted);
+        }
+
+        // el owner registra la devolucion cuando el renter no la envia, queda confirmada
+        let reading = UsageReading {
+            odometer_km,
+            fuel_level_pct,
+            confirmed: true,
+        };
+
+        validate_reading(&reading, &rental.pickup_reading)?;
+        rental.return_reading = Some(reading.clone());
+
+        write_rental(env, &renter, &owner, &rental);
+
+        crate::events::usage::usage_reading_recorded(env, rental.id, ReadingKind::Return, reading);
+        Ok(())
+    }
+
+    fn get_usage_reading(env: &Env, renter: Address, owner: Address, kind: ReadingKind) -> Result<UsageReading, Error> {
+        let rental = read_rental(env, &renter, &owner)?;
+
+        match kind {
+            ReadingKind::Pickup => rental.pickup_reading,
+            ReadingKind::Return => rental.return_reading,
+        }
+        .ok_or(Error::ReadingNotSubmitted)
+    }
+
+    fn get_usage_debt(env: &Env, renter: Address, owner: Address) -> i128 {
+        read_usage_debt(env, &renter, &owner)
+    }
+
+    fn settle_usage_debt(env: &Env, renter: Address, owner: Address) -> Result<i128, Error> {
+        renter.require_auth();
+
+        let debt = read_usage_debt(env, &renter, &owner);
+
+        if debt <= 0 {
+            return Err(Error::NoUsageDebt);
+        }
+
+        let mut car = read_car(env, &owner)?;
+
+        if has_co_owners(env, &owner) {
+            split_owner_credit(env, &owner, debt);
+        } else {
+            car.available_to_withdraw += debt;
+            write_car(env, &owner, &car);
+        }
+
+        let previous_balance = read_contract_balance(env);
+
+        write_usage_debt(env, &renter, &owner, 0);
+        write_renter_usage_debt(env, &renter, read_renter_usage_debt(env, &renter) - debt);
+        write_contract_balance(env, &(previous_balance + debt));
+
+        token_transfer(env, &renter, &env.current_contract_address(), &debt)?;
+        crate::events::balance::contract_balance_changed(env, previous_balance, previous_balance + debt);
+        crate::events::usage::usage_debt_settled(env, renter, owner, debt);
+        Ok(debt)
+    }
+
+    fn submit_condition_report(
+        env: &Env,
+        renter: Address,
+        owner: Address,
+        kind: ReadingKind,
+        evidence: Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        // el reporte lo firman ambas partes en la misma transaccion
+        renter.require_auth();
+
+        let car = read_car(env, &owner)?;
+        let holder = require_car_holder(env, &car);
+
+        let rental = read_rental(env, &renter, &owner)?;
+
+        validate_evidence(&evidence)?;
+
+        // una vez firmado no se puede reemplazar
+        if has_condition_report(env, rental.id, &kind) {
+            return Err(Error::ConditionReportExists);
+        }
+
+        let report = ConditionReport {
+            renter: renter.clone(),
+            holder: holder.clone(),
+            evidence: evidence.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        write_condition_report(env, rental.id, &kind, &report);
+
+        crate::events::condition::condition_reported(env, rental.id, kind, renter, holder, evidence);
+        Ok(())
+    }
+
+    fn get_condition_report(env: &Env, rental_id: u32, kind: ReadingKind) -> Result<ConditionReport, Error> {
+        read_condition_report(env, rental_id, &kind)
+    }
+
+    fn set_car_device(env: &Env, owner: Address, public_key: BytesN<32>) -> Result<(), Error> {
+        let car = read_car(env, &owner)?;
+        require_car_holder(env, &car);
+
+        write_car_device(env, &owner, &public_key);
+
+        crate::events::device::device_registered(env, owner, car.token_id, public_key);
+        Ok(())
+    }
+
+    fn get_car_device(env: &Env, owner: Address) -> Result<BytesN<32>, Error> {
+        read_car_device(env, &owner)
+    }
+
+    fn report_device_event(
+        env: &Env,
+        owner: Address,
+        renter: Address,
+        action: DeviceAction,
+        timestamp: u64,
+        nonce: u64,
+        signature: BytesN<64>,
+    ) -> Result<(), Error> {
+        // no hace falta require_auth, la firma del dispositivo es la autorizacion
+        let mut car = read_car(env, &owner)?;
+        let mut rental = read_rental(env, &renter, &owner)?;
+
+        verify_device_report(env, &owner, rental.id, &action, timestamp, nonce, &signature)?;
+
+        match action {
+            DeviceAction::Unlock => {
+                if rental.started_at.is_none() {
+                    start_rental(env, &owner, &mut car, &mut rental, timestamp);
+                    write_car(env, &owner, &car);
+                }
+            }
+            DeviceAction::Lock => {
+                if rental.started_at.is_none() {
+                    return Err(Error::RentalNotStarted);
+                }
+
+                rental.ended_at = Some(timestamp);
+            }
+        }
+
+        write_rental(env, &renter, &owner, &rental);
+
+        crate::events::device::device_report(env, car.token_id, rental.id, action, timestamp, nonce);
+        Ok(())
+    }
+
+    fn set_pickup_code_required(env: &Env, owner: Address, required: bool) -> Result<(), Error> {
+        let mut car = read_car(env, &owner)?;
+        require_car_holder(env, &car);
+
+        car.pickup_code_required = required;
+        write_car(env, &owner, &car);
+        Ok(())
+    }
+
+    fn commit_pickup_code(env: &Env, owner: Address, renter: Address, code_hash: BytesN<32>) -> Result<(), Error> {
+        let car = read_car(env, &owner)?;
+        require_car_holder(env, &car);
+
+        let mut rental = read_rental(env, &renter, &owner)?;
+
+        if rental.started_at.is_some() {
+            return Err(Error::PickupAlreadyConfirmed);
+        }
+
+        rental.pickup_code_hash = Some(code_hash.clone());
+        write_rental(env, &renter, &owner, &rental);
+
+        crate::events::pickup::pickup_code_committed(env, renter, rental.id, code_hash);
+        Ok(())
+    }
+
+    fn confirm_pickup(env: &Env, renter: Address, owner: Address, code: Bytes) -> Result<(), Error> {
+        renter.require_auth();
+
+        let mut car = read_car(env, &owner)?;
+        let mut rental = read_rental(env, &renter, &owner)?;
+
+        if rental.started_at.is_some() {
+            return Err(Error::PickupAlreadyConfirmed);
+        }
+
+        let Some(code_hash) = rental.pickup_code_hash.clone() else {
+            return Err(Error::PickupCodeNotCommitted);
+        };
+
+        let revealed: BytesN<32> = env.crypto().sha256(&code).into();
+
+        if revealed != code_hash {
+            return Err(Error::InvalidPickupCode);
+        }
+
+        let released_credit = rental.pending_credit;
+        let started_at = env.ledger().timestamp();
+
+        start_rental(env, &owner, &mut car, &mut rental, started_at);
+        write_car(env, &owner, &car);
+        write_rental(env, &renter, &owner, &rental);
+
+        crate::events::pickup::pickup_confirmed(env, renter, owner, rental.id, started_at, released_credit);
+        Ok(())
+    }
+
+    fn set_rental_limits(
+        env: &Env,
+        owner: Address,
+        min_days: u32,
+        max_days: u32,
+        min_notice_secs: u64,
+    ) -> Result<(), Error> {
+        if max_days > 0 && max_days < min_days {
+            return Err(Error::InvalidRentalLimits);
+        }
+
+        let mut car = read_car(env, &owner)?;
+        require_car_holder(env, &car);
+
+        car.min_days = min_days;
+        car.max_days = max_days;
+        car.min_notice_secs = min_notice_secs;
+        write_car(env, &owner, &car);
+        Ok(())
+    }
+
+    fn set_driver_fee(env: &Env, owner: Address, fee_per_day: i128) -> Result<(), Error> {
+        if fee_per_day < 0 {
+            return Err(Error::InvalidDriverFee);
+        }
+
+        let mut car = read_car(env, &owner)?;
+        require_car_holder(env, &car);
+
+        car.driver_fee_per_day = fee_per_day;
+        write_car(env, &owner, &car);
+        Ok(())
+    }
+
+    fn add_rental_driver(env: &Env, renter: Address, owner: Address, driver: Address) -> Result<(), Error> {
+        renter.require_auth();
+
+        let car = read_car(env, &owner)?;
+        let mut rental = read_rental(env, &renter, &owner)?;
+
+        if rental.drivers.len() >= MAX_ADDITIONAL_DRIVERS {
+            return Err(Error::TooManyDrivers);
+        }
+
+        if driver == renter || rental.drivers.iter().any(|d| d.address == driver) {
+            return Err(Error::DriverAlreadyAdded);
+        }
+
+        // el cargo queda retenido hasta que el conductor acepte
+        let fee = car.driver_fee_per_day * rental.total_days_to_rent as i128;
+
+        rental.drivers.push_back(RentalDriver {
+            address: driver.clone(),
+            accepted: false,
+            fee,
+        });
+
+        write_rental(env, &renter, &owner, &rental);
+
+        if fee > 0 {
+            token_transfer(&env, &renter, &env.current_contract_address(), &fee)?;
+        }
+
+        crate::events::driver::driver_invited(env, renter, driver, rental.id, fee);
+        Ok(())
+    }
+
+    fn accept_rental_driver(env: &Env, driver: Address, renter: Address, owner: Address) -> Result<(), Error> {
+        driver.require_auth();
+
+        let mut car = read_car(env, &owner)?;
+        let mut rental = read_rental(env, &renter, &owner)?;
+
+        let Some(index) = rental.drivers.iter().position(|d| d.address == driver && !d.accepted) else {
+            return Err(Error::DriverNotInvited);
+        };
+
+        let mut invite = rental.drivers.get_unchecked(index as u32);
+        invite.accepted = true;
+        rental.drivers.set(index as u32, invite.clone());
+
+        write_rental(env, &renter, &owner, &rental);
+
+        // antes de la recogida el cargo queda retenido, start_rental o complete_rental lo liberan
+        if rental.started_at.is_some() {
+            release_driver_fees(env, &owner, &mut car, invite.fee);
+            write_car(env, &owner, &car);
+        }
+
+        crate::events::driver::driver_accepted(env, driver, rental.id);
+        Ok(())
+    }
+
+    fn get_rental_drivers(env: &Env, renter: Address, owner: Address) -> Result<Vec<Address>, Error> {
+        let rental = read_rental(env, &renter, &owner)?;
+        let mut drivers = Vec::new(env);
+
+        // solo los que aceptaron pueden manejar, el renter siempre puede
+        for driver in rental.drivers.iter().filter(|d| d.accepted) {
+            drivers.push_back(driver.address);
+        }
+
+        Ok(drivers)
+    }
+
+    fn payout_owner(env: &Env, owner: Address, amount: i128) -> Result<(), Error> {
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        let mut car = read_car(&env, &owner)?;
+        require_car_holder(env, &car);
+
+        // valida que el usuario no pueda hacer payout si el carro sigue rentado
+        if car.car_status == CarStatus::Rented {
+            return Err(Error::CannotPayoutWhileCarIsRented)
+        }
+
+        if amount > car.available_to_withdraw {
+            return Err(Error::InsufficientBalance);
+        }
+
+
+        let mut contract_balance = read_contract_balance(&env);
+
+        if amount > contract_balance {
+            return Err(Error::BalanceNotAvailableForAmountRequested);
+        }
+
+        let previous_balance = contract_balance;
+
+        car.available_to_withdraw -= amount;
+        contract_balance -= amount;
+
+        write_car(&env, &owner, &car);
+        write_contract_balance(&env, &contract_balance);
+
+        token_transfer(&env, &env.current_contract_address(), &car.payout_address, &amount)?;
+        crate::events::balance::contract_balance_changed(env, previous_balance, contract_balance);
+        crate::events::payout_owner::payout(env, owner, car.token_id, car.payout_address.clone(), amount);
+        Ok(())
+    }
+
+    fn withdraw_all(env: &Env, holder: Address) -> Result<i128, Error> {
+        holder.require_auth();
+
+        // barre cada carro cuyo NFT tiene el holder, no solo el que registro
+        let cars = read_holder_cars(env, &holder);
+
+        if cars.is_empty() {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut total = 0_i128;
+
+        for owner in cars.iter() {
+            total += sweep_owner_balance(env, &owner)?;
+        }
+
+        crate::events::withdraw_all::withdrew_all(env, holder, cars.len(), total);
+        Ok(total)
+    }
+
+    fn batch_payout(env: &Env, caller: Address, owners: Vec<Address>) -> Result<i128, Error> {
+        require_role(env, &caller, &[Role::Admin, Role::Treasurer])?;
+
+        let mut total = 0_i128;
+
+        // los owners sin carro se ignoran para que un solo owner removido no frene el cierre de mes
+        for owner in owners.iter() {
+            if has_car(env, &owner) {
+                total += sweep_owner_balance(env, &owner)?;
+            }
+        }
+
+        crate::events::withdraw_all::batch_paid_out(env, caller, owners.len(), total);
+        Ok(total)
+    }
+
+    fn set_payout_address(env: &Env, owner: Address, payout_address: Address) -> Result<(), Error> {
+        let mut car = read_car(env, &owner)?;
+        require_car_holder(env, &car);
+
+        car.payout_address = payout_address.clone();
+        write_car(env, &owner, &car);
+
+        crate::events::payout_address::payout_address_changed(env, owner, car.token_id, payout_address);
+        Ok(())
+    }
+
+    fn update_price(env: &Env, owner: Address, price_per_day: i128) -> Result<(), Error> {
+        if price_per_day <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        let mut car = read_car(env, &owner)?;
+        require_car_holder(env, &car);
+
+        car.price_per_day = price_per_day;
+        write_car(env, &owner, &car);
+
+        crate::events::add_car::price_updated(env, owner, car.token_id, price_per_day);
+        Ok(())
+    }
+
+    fn get_car_token_id(env: &Env, owner: Address) -> Result<u32, Error> {
+        Ok(read_car(env, &owner)?.token_id)
+    }
+
+    fn get_car_holder(env: &Env, owner: Address) -> Result<Address, Error> {
+        Ok(read_car_holder(env, &read_car(env, &owner)?))
+    }
+
+    fn get_holder_credit(env: &Env, holder: Address) -> i128 {
+        read_holder_credit(env, &holder)
+    }
+
+    fn withdraw_holder_credit(env: &Env, holder: Address, amount: i128) -> Result<(), Error> {
+        holder.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        let credit = read_holder_credit(env, &holder);
+
+        if amount > credit {
+            return Err(Error::InsufficientBalance);
+        }
+
+        let contract_balance = read_contract_balance(env);
+
+        if amount > contract_balance {
+            return Err(Error::BalanceNotAvailableForAmountRequested);
+        }
+
+        write_holder_credit(env, &holder, credit - amount);
+        write_contract_balance(env, &(contract_balance - amount));
+
+        token_transfer(env, &env.current_contract_address(), &holder, &amount)?;
+        crate::events::balance::contract_balance_changed(env, contract_balance, contract_balance - amount);
+        crate::events::holder_credit::holder_credit_paid_out(env, holder, amount);
+        Ok(())
+    }
+
+    fn set_co_owners(env: &Env, owner: Address, co_owners: Vec<CoOwner>) -> Result<(), Error> {
+        let car = read_car(env, &owner)?;
+
+        // la primera tabla la define el holder, despues solo se cambia con la firma de todos
+        if has_co_owners(env, &owner) {
+            for co_owner in read_co_owners(env, &owner).iter() {
+                co_owner.address.require_auth();
+            }
+        } else {
+            require_car_holder(env, &car);
+        }
+
+        validate_co_owners(&co_owners)?;
+
+        write_co_owners(env, &owner, &co_owners);
+        crate::events::co_owner::co_owners_updated(env, owner, car.token_id, co_owners);
+        Ok(())
+    }
+
+    fn get_co_owners(env: &Env, owner: Address) -> Vec<CoOwner> {
+        read_co_owners(env, &owner)
+    }
+
+    fn get_co_owner_balance(env: &Env, owner: Address, co_owner: Address) -> i128 {
+        read_co_owner_balance(env, &owner, &co_owner)
+    }
+
+    fn withdraw_co_owner_balance(env: &Env, owner: Address, co_owner: Address) -> Result<i128, Error> {
+        co_owner.require_auth();
+
+        if read_car(env, &owner).is_ok_and(|car| car.car_status == CarStatus::Rented) {
+            return Err(Error::CannotPayoutWhileCarIsRented);
+        }
+
+        let balance = read_co_owner_balance(env, &owner, &co_owner);
+
+        if balance <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+
+        let contract_balance = read_contract_balance(env);
+
+        if balance > contract_balance {
+            return Err(Error::BalanceNotAvailableForAmountRequested);
+        }
+
+        write_co_owner_balance(env, &owner, &co_owner, 0);
+        write_contract_balance(env, &(contract_balance - balance));
+
+        token_transfer(env, &env.current_contract_address(), &co_owner, &balance)?;
+        crate::events::balance::contract_balance_changed(env, contract_balance, contract_balance - balance);
+        crate::events::co_owner::co_owner_paid_out(env, owner, co_owner, balance);
+        Ok(balance)
+    }
+
+    fn set_zone(env: &Env, caller: Address, zone_id: u32, name: String, geohash_prefix: String) -> Result<(), Error> {
+        require_admin(env, &caller)?;
+        validate_zone(&name, &geohash_prefix)?;
+
+        let zone = Zone {
+            name: name.clone(),
+            geohash_prefix: geohash_prefix.clone(),
+        };
+
+        write_zone(env, zone_id, &zone);
+
+        crate::events::zone::zone_updated(env, zone_id, name, geohash_prefix);
+        Ok(())
+    }
+
+    fn remove_zone(env: &Env, caller: Address, zone_id: u32) -> Result<(), Error> {
+        require_admin(env, &caller)?;
+
+        if !has_zone(env, zone_id) {
+            return Err(Error::ZoneNotFound);
+        }
+
+        // primero hay que mover los carros a otra zona
+        if !read_zone_cars(env, zone_id).is_empty() {
+            return Err(Error::ZoneNotEmpty);
+        }
+
+        remove_zone(env, zone_id);
+
+        crate::events::zone::zone_removed(env, zone_id);
+        Ok(())
+    }
+
+    fn get_zone(env: &Env, zone_id: u32) -> Result<Zone, Error> {
+        read_zone(env, zone_id)
+    }
+
+    fn set_car_location(
+        env: &Env,
+        caller: Address,
+        owner: Address,
+        zone_id: u32,
+        pickup_point: String,
+    ) -> Result<(), Error> {
+        let mut car = read_car(env, &owner)?;
+
+        // el holder ubica su carro, el admin o el fleet manager pueden reubicar cualquiera
+        if caller == read_car_holder(env, &car) {
+            caller.require_auth();
+        } else {
+            require_role(env, &caller, &[Role::Admin, Role::FleetManager])?;
+        }
+
+        validate_car_location(env, zone_id, &pickup_point)?;
+
+        if let Some(location) = car.location {
+            remove_from_zone_index(env, location.zone_id, &owner);
+        }
+
+        add_to_zone_index(env, zone_id, &owner);
+
+        car.location = Some(CarLocation {
+            zone_id,
+            pickup_point: pickup_point.clone(),
+        });
+        write_car(env, &owner, &car);
+
+        crate::events::zone::car_location_changed(env, owner, car.token_id, zone_id, pickup_point);
+        Ok(())
+    }
+
+    fn get_car_location(env: &Env, owner: Address) -> Result<CarLocation, Error> {
+        read_car(env, &owner)?.location.ok_or(Error::CarLocationNotSet)
+    }
+
+    fn list_cars_in_zone(env: &Env, zone_id: u32, start: u32, limit: u32) -> Result<Vec<Address>, Error> {
+        if !has_zone(env, zone_id) {
+            return Err(Error::ZoneNotFound);
+        }
+
+        Ok(paginate(env, &read_zone_cars(env, zone_id), start, limit))
+    }
+
+    fn set_category_rules(
+        env: &Env,
+        caller: Address,
+        category: CarCategory,
+        min_verification_level: u32,
+        max_rental_days: u32,
+        deposit_floor: i128,
+    ) -> Result<(), Error> {
+        require_admin(env, &caller)?;
+
+        let rules = CategoryRules {
+            min_verification_level,
+            max_rental_days,
+            deposit_floor,
+        };
+
+        validate_category_rules(&rules)?;
+        write_category_rules(env, &category, &rules);
+
+        crate::events::category::category_rules_updated(env, category, rules);
+        Ok(())
+    }
+
+    fn get_category_rules(env: &Env, category: CarCategory) -> Result<CategoryRules, Error> {
+        read_category_rules(env, &category)
+    }
+
+    fn set_renter_verification(env: &Env, caller: Address, renter: Address, level: u32) -> Result<(), Error> {
+        require_admin(env, &caller)?;
+
+        write_renter_verification(env, &renter, level);
+
+        crate::events::category::renter_verified(env, renter, level);
+        Ok(())
+    }
+
+    fn get_renter_verification(env: &Env, renter: Address) -> u32 {
+        read_renter_verification(env, &renter)
+    }
+
+    fn list_cars_in_category(env: &Env, category: CarCategory, start: u32, limit: u32) -> Vec<Address> {
+        paginate(env, &read_category_cars(env, &category), start, limit)
+    }
+
+    fn get_payout_address(env: &Env, owner: Address) -> Result<Address, Error> {
+        Ok(read_car(env, &owner)?.payout_address)
+    }
+
+    fn payout_admin(env: &Env, admin: Address, amount: i128) -> Result<(), Error> {
+        // la treasury configurada tambien puede barrer las comisiones
+        if read_treasury(env).is_ok_and(|treasury| admin == treasury) {
+            admin.require_auth();
+        } else {
+            require_role(env, &admin, &[Role::Admin, Role::Treasurer])?;
+        }
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        // retiros grandes tienen que pasar por propose_withdrawal / approve_withdrawal,
+        // se suma lo retirado en el periodo para que no se pueda partir en varios retiros chicos
+        let window = if let Ok(policy) = read_withdrawal_policy(env) {
+            let mut window = read_withdrawal_window(env, policy.period_secs);
+
+            if window.withdrawn + amount > policy.amount_threshold {
+                return Err(Error::WithdrawalRequiresApproval);
+            }
+
+            window.withdrawn += amount;
+            Some(window)
+        } else {
+            None
+        };
+
+        let mut available_commision = read_commission(&env);
+
+        if amount > read_commission(&env) {
+            return Err(Error::InsufficientBalance);
+        }
+
+        available_commision -= amount;
+
+        write_commission(&env, available_commision);
+
+        if let Some(window) = window {
+            write_withdrawal_window(env, &window);
+        }
+
+        let recipient = read_commission_recipient(env, &admin);
+
+        token_transfer(&env, &env.current_contract_address(), &recipient, &amount)?;
+        crate::events::balance::admin_commission_changed(env, available_commision + amount, available_commision);
+        crate::events::payout_admin::payout(env, recipient, amount);
+        Ok(())
+    }
+
+    fn set_treasury(env: &Env, caller: Address, treasury: Address) -> Result<(), Error> {
+        require_admin(env, &caller)?;
+
+        write_treasury(env, &treasury);
+
+        crate::events::treasury::treasury_changed(env, caller, treasury);
+        Ok(())
+    }
+
+    fn get_treasury(env: &Env) -> Result<Address, Error> {
+        read_treasury(env)
+    }
+
+    fn remove_car(env: &Env, caller: Address, owner: Address) -> Result<(), Error> {
+        // el holder del NFT puede deslistar su propio carro
+        let is_holder = read_car(env, &owner).is_ok_and(|car| caller == read_car_holder(env, &car));
+
+        if is_holder {
+            caller.require_auth();
+        } else {
+            require_role(env, &caller, &[Role::Admin, Role::FleetManager])?;
+        }
+
+        // el NFT no se quema, solo deja de apuntar a un carro listado
+        let car = read_car(env, &owner)?;
+
+        // borrar un carro rentado deja la renta huerfana
+        if car.car_status == CarStatus::Rented {
+            return Err(Error::CarAlreadyRented);
+        }
+
+        remove_from_holder_index(env, &read_car_holder(env, &car), &owner);
+        remove_car(env, &owner);
+        remove_car_token(env, car.token_id);
+        remove_pricing_rules(env, &owner);
+        remove_discount_tiers(env, &owner);
+        remove_usage_fees(env, &owner);
+        remove_car_device(env, &owner);
+        remove_co_owners(env, &owner);
+        remove_from_category_index(env, &car.category, &owner);
+
+        if let Some(location) = car.location {
+            remove_from_zone_index(env, location.zone_id, &owner);
+        }
+
+        crate::events::remove_car::car_removed(env, owner, car.token_id);
+        Ok(())
+    }
+
+    fn set_withdrawal_policy(
+        env: &Env,
+        caller: Address,
+        approvers: Vec<Address>,
+        required_approvals: u32,
+        amount_threshold: i128,
+        proposal_ttl: u64,
+        period_secs: u64,
+    ) -> Result<(), Error> {
+        require_admin(env, &caller)?;
+
+        if required_approvals == 0 || required_approvals > approvers.len() || amount_threshold < 0 || proposal_ttl == 0 || period_secs == 0 {
+            return Err(Error::InvalidWithdrawalPolicy);
+        }
+
+        // un approver repetido contaria dos veces para llegar a required_approvals
+        if approvers.iter().any(|approver| approvers.first_index_of(&approver) != approvers.last_index_of(&approver)) {
+            return Err(Error::InvalidWithdrawalPolicy);
+        }
+
+        let policy = WithdrawalPolicy {
+            approvers,
+            required_approvals,
+            amount_threshold,
+            proposal_ttl,
+            period_secs,
+        };
+
+        write_withdrawal_policy(env, &policy);
+        Ok(())
+    }
+
+    fn get_withdrawal_policy(env: &Env) -> Result<WithdrawalPolicy, Error> {
+        read_withdrawal_policy(env)
+    }
+
+    fn propose_withdrawal(env: &Env, proposer: Address, amount: i128) -> Result<u32, Error> {
+        require_role(env, &proposer, &[Role::Admin, Role::Treasurer])?;
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        let policy = read_withdrawal_policy(env)?;
+
+        if amount > read_commission(env) {
+            return Err(Error::InsufficientBalance);
+        }
+
+        let id = next_withdrawal_id(env);
+        let expires_at = env.ledger().timestamp() + policy.proposal_ttl;
+
+        let proposal = WithdrawalProposal {
+            proposer: proposer.clone(),
+            amount,
+            approvals: Vec::new(env),
+            expires_at,
+            status: WithdrawalStatus::Pending,
+        };
+
+        write_withdrawal(env, id, &proposal);
+
+        crate::events::withdrawal::withdrawal_proposed(env, id, proposer, amount, expires_at);
+        Ok(id)
+    }
+
+    fn approve_withdrawal(env: &Env, approver: Address, id: u32) -> Result<(), Error> {
+        approver.require_auth();
+
+        let mut proposal = read_withdrawal(env, id)?;
+        let policy = read_withdrawal_policy(env)?;
+
+        if !policy.approvers.contains(&approver) {
+            return Err(Error::NotAnApprover);
+        }
+
+        if proposal.status != WithdrawalStatus::Pending {
+            return Err(Error::WithdrawalNotPending);
+        }
+
+        if env.ledger().timestamp() > proposal.expires_at {
+            return Err(Error::WithdrawalExpired);
+        }
+
+        if proposal.approvals.contains(&approver) {
+            return Err(Error::WithdrawalAlreadyApproved);
+        }
+
+        proposal.approvals.push_back(approver.clone());
+        crate::events::withdrawal::withdrawal_approved(env, id, approver, proposal.approvals.len());
+
+        // con la ultima aprobacion necesaria se ejecuta el retiro
+        if proposal.approvals.len() >= policy.required_approvals {
+            let available_commision = read_commission(env);
+
+            if proposal.amount > available_commision {
+                return Err(Error::InsufficientBalance);
+            }
+
+            write_commission(env, available_commision - proposal.amount);
+            proposal.status = WithdrawalStatus::Executed;
+            write_withdrawal(env, id, &proposal);
+
+            let recipient = read_commission_recipient(env, &proposal.proposer);
+
+            token_transfer(env, &env.current_contract_address(), &recipient, &proposal.amount)?;
+            crate::events::balance::admin_commission_changed(env, available_commision, available_commision - proposal.amount);
+            crate::events::withdrawal::withdrawal_executed(env, id, recipient, proposal.amount);
+            return Ok(());
+        }
+
+        write_withdrawal(env, id, &proposal);
+        Ok(())
+    }
+
+    fn cancel_withdrawal(env: &Env, caller: Address, id: u32) -> Result<(), Error> {
+        caller.require_auth();
+
+        let mut proposal = read_withdrawal(env, id)?;
+
+        // solo quien propuso o el admin pueden cancelar
+        if caller != proposal.proposer && caller != read_admin(env)? {
+            return Err(Error::Unauthorized);
+        }
+
+        if proposal.status != WithdrawalStatus::Pending {
+            return Err(Error::WithdrawalNotPending);
+        }
+
+        proposal.status = WithdrawalStatus::Cancelled;
+        write_withdrawal(env, id, &proposal);
+
+        crate::events::withdrawal::withdrawal_cancelled(env, id, caller);
+        Ok(())
+    }
+
+    fn get_withdrawal(env: &Env, id: u32) -> Result<WithdrawalProposal, Error> {
+        read_withdrawal(env, id)
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl NonFungibleToken for RentACarContract {
+    type ContractType = Base;
+
+    // al transferir el NFT los payouts futuros del carro pasan al nuevo holder
+    fn transfer(e: &Env, from: Address, to: Address, token_id: u32) {
+        Base::transfer(e, &from, &to, token_id);
+        on_car_token_transfer(e, token_id, &from, &to);
+    }
+
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: u32) {
+        Base::transfer_from(e, &spender, &from, &to, token_id);
+        on_car_token_transfer(e, token_id, &from, &to);
+    }
+}
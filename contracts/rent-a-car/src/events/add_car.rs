@@ -1,10 +1,37 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CarAddedEvent {
+    pub owner: Address,
+    pub car_id: u32,
+    pub price_per_day: i128,
+}
+
+pub(crate) fn car_added(env: &Env, owner: Address, car_id: u32, price_per_day: i128) {
+    let topics = (Symbol::new(env, "car_added"), EVENT_VERSION, owner.clone());
+
+    env.events().publish(
+        topics,
+        CarAddedEvent { owner, car_id, price_per_day }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PriceUpdatedEvent {
+    pub owner: Address,
+    pub car_id: u32,
+    pub price_per_day: i128,
+}
+
+pub(crate) fn price_updated(env: &Env, owner: Address, car_id: u32, price_per_day: i128) {
+    let topics = (Symbol::new(env, "price_updated"), EVENT_VERSION, owner.clone());
 
-pub(crate) fn car_added(env: &Env, owner: Address, price_per_day: i128) {
-    let topics = (Symbol::new(env, "car_added"), owner.clone());
-    
     env.events().publish(
         topics,
-        price_per_day
+        PriceUpdatedEvent { owner, car_id, price_per_day }
     );
-}
\ No newline at end of file
+}
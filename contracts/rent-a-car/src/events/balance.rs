@@ -0,0 +1,35 @@
+use soroban_sdk::{contracttype, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ContractBalanceChangedEvent {
+    pub previous: i128,
+    pub current: i128,
+}
+
+pub(crate) fn contract_balance_changed(env: &Env, previous: i128, current: i128) {
+    let topics = (Symbol::new(env, "contract_balance"), EVENT_VERSION);
+
+    env.events().publish(
+        topics,
+        ContractBalanceChangedEvent { previous, current }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct AdminCommissionChangedEvent {
+    pub previous: i128,
+    pub current: i128,
+}
+
+pub(crate) fn admin_commission_changed(env: &Env, previous: i128, current: i128) {
+    let topics = (Symbol::new(env, "admin_commission"), EVENT_VERSION);
+
+    env.events().publish(
+        topics,
+        AdminCommissionChangedEvent { previous, current }
+    );
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+use crate::storage::{structs::category::CategoryRules, types::car_category::CarCategory};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CategoryRulesUpdatedEvent {
+    pub category: CarCategory,
+    pub rules: CategoryRules,
+}
+
+pub(crate) fn category_rules_updated(env: &Env, category: CarCategory, rules: CategoryRules) {
+    let topics = (Symbol::new(env, "category_rules_updated"), EVENT_VERSION, category.clone());
+
+    env.events().publish(
+        topics,
+        CategoryRulesUpdatedEvent { category, rules }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RenterVerifiedEvent {
+    pub renter: Address,
+    pub level: u32,
+}
+
+pub(crate) fn renter_verified(env: &Env, renter: Address, level: u32) {
+    let topics = (Symbol::new(env, "renter_verified"), EVENT_VERSION, renter.clone());
+
+    env.events().publish(
+        topics,
+        RenterVerifiedEvent { renter, level }
+    );
+}
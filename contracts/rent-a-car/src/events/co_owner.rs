@@ -0,0 +1,38 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::storage::structs::co_owner::CoOwner;
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CoOwnersUpdatedEvent {
+    pub owner: Address,
+    pub car_id: u32,
+    pub co_owners: Vec<CoOwner>,
+}
+
+pub(crate) fn co_owners_updated(env: &Env, owner: Address, car_id: u32, co_owners: Vec<CoOwner>) {
+    let topics = (Symbol::new(env, "co_owners_updated"), EVENT_VERSION, owner.clone());
+
+    env.events().publish(
+        topics,
+        CoOwnersUpdatedEvent { owner, car_id, co_owners }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CoOwnerPayoutEvent {
+    pub owner: Address,
+    pub co_owner: Address,
+    pub amount: i128,
+}
+
+pub(crate) fn co_owner_paid_out(env: &Env, owner: Address, co_owner: Address, amount: i128) {
+    let topics = (Symbol::new(env, "co_owner_payout"), EVENT_VERSION, owner.clone(), co_owner.clone());
+
+    env.events().publish(
+        topics,
+        CoOwnerPayoutEvent { owner, co_owner, amount }
+    );
+}
@@ -0,0 +1,25 @@
+use soroban_sdk::{contracttype, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CommissionAccruedEvent {
+    pub rental_id: u32,
+    pub commission: i128,
+    pub admin_commission: i128,
+}
+
+pub(crate) fn commission_accrued(
+    env: &Env,
+    rental_id: u32,
+    commission: i128,
+    admin_commission: i128
+) {
+    let topics = (Symbol::new(env, "commission_accrued"), EVENT_VERSION, rental_id);
+
+    env.events().publish(
+        topics,
+        CommissionAccruedEvent { rental_id, commission, admin_commission }
+    );
+}
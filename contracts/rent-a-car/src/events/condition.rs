@@ -0,0 +1,30 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, Symbol, Vec};
+
+use crate::events::EVENT_VERSION;
+use crate::storage::types::reading_kind::ReadingKind;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ConditionReportedEvent {
+    pub rental_id: u32,
+    pub kind: ReadingKind,
+    pub renter: Address,
+    pub holder: Address,
+    pub evidence: Vec<BytesN<32>>,
+}
+
+pub(crate) fn condition_reported(
+    env: &Env,
+    rental_id: u32,
+    kind: ReadingKind,
+    renter: Address,
+    holder: Address,
+    evidence: Vec<BytesN<32>>,
+) {
+    let topics = (Symbol::new(env, "condition_reported"), EVENT_VERSION, rental_id);
+
+    env.events().publish(
+        topics,
+        ConditionReportedEvent { rental_id, kind, renter, holder, evidence }
+    );
+}
@@ -1,10 +1,19 @@
- use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ContractInitializedEvent {
+    pub admin: Address,
+    pub token: Address,
+}
 
 pub(crate) fn contract_initialized(env: &Env, admin: Address, token: Address) {
-    let topics = (Symbol::new(env, "contract_initialized"),);
+    let topics = (Symbol::new(env, "contract_initialized"), EVENT_VERSION);
 
     env.events().publish(
         topics,
-        (admin, token)
+        ContractInitializedEvent { admin, token }
     );
-}
\ No newline at end of file
+}
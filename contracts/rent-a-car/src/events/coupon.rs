@@ -0,0 +1,37 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, Symbol};
+
+use crate::storage::structs::coupon::Coupon;
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CouponCreatedEvent {
+    pub code_hash: BytesN<32>,
+    pub coupon: Coupon,
+}
+
+pub(crate) fn coupon_created(env: &Env, code_hash: BytesN<32>, coupon: Coupon) {
+    let topics = (Symbol::new(env, "coupon_created"), EVENT_VERSION, code_hash.clone());
+
+    env.events().publish(
+        topics,
+        CouponCreatedEvent { code_hash, coupon }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CouponRedeemedEvent {
+    pub code_hash: BytesN<32>,
+    pub renter: Address,
+    pub discount: i128,
+}
+
+pub(crate) fn coupon_redeemed(env: &Env, code_hash: BytesN<32>, renter: Address, discount: i128) {
+    let topics = (Symbol::new(env, "coupon_redeemed"), EVENT_VERSION, code_hash.clone(), renter.clone());
+
+    env.events().publish(
+        topics,
+        CouponRedeemedEvent { code_hash, renter, discount }
+    );
+}
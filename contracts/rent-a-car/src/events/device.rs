@@ -0,0 +1,40 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+use crate::storage::types::device_action::DeviceAction;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DeviceRegisteredEvent {
+    pub owner: Address,
+    pub car_id: u32,
+    pub public_key: BytesN<32>,
+}
+
+pub(crate) fn device_registered(env: &Env, owner: Address, car_id: u32, public_key: BytesN<32>) {
+    let topics = (Symbol::new(env, "device_registered"), EVENT_VERSION, owner.clone());
+
+    env.events().publish(
+        topics,
+        DeviceRegisteredEvent { owner, car_id, public_key }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DeviceReportEvent {
+    pub car_id: u32,
+    pub rental_id: u32,
+    pub action: DeviceAction,
+    pub timestamp: u64,
+    pub nonce: u64,
+}
+
+pub(crate) fn device_report(env: &Env, car_id: u32, rental_id: u32, action: DeviceAction, timestamp: u64, nonce: u64) {
+    let topics = (Symbol::new(env, "device_report"), EVENT_VERSION, car_id, rental_id);
+
+    env.events().publish(
+        topics,
+        DeviceReportEvent { car_id, rental_id, action, timestamp, nonce }
+    );
+}
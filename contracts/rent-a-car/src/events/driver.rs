@@ -0,0 +1,37 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DriverInvitedEvent {
+    pub renter: Address,
+    pub driver: Address,
+    pub rental_id: u32,
+    pub fee: i128,
+}
+
+pub(crate) fn driver_invited(env: &Env, renter: Address, driver: Address, rental_id: u32, fee: i128) {
+    let topics = (Symbol::new(env, "driver_invited"), EVENT_VERSION, rental_id, driver.clone());
+
+    env.events().publish(
+        topics,
+        DriverInvitedEvent { renter, driver, rental_id, fee }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DriverAcceptedEvent {
+    pub driver: Address,
+    pub rental_id: u32,
+}
+
+pub(crate) fn driver_accepted(env: &Env, driver: Address, rental_id: u32) {
+    let topics = (Symbol::new(env, "driver_accepted"), EVENT_VERSION, rental_id, driver.clone());
+
+    env.events().publish(
+        topics,
+        DriverAcceptedEvent { driver, rental_id }
+    );
+}
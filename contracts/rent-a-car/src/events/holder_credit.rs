@@ -0,0 +1,36 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct HolderCreditSettledEvent {
+    pub holder: Address,
+    pub car_id: u32,
+    pub amount: i128,
+}
+
+pub(crate) fn holder_credit_settled(env: &Env, holder: Address, car_id: u32, amount: i128) {
+    let topics = (Symbol::new(env, "holder_credit_settled"), EVENT_VERSION, holder.clone());
+
+    env.events().publish(
+        topics,
+        HolderCreditSettledEvent { holder, car_id, amount }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct HolderCreditPayoutEvent {
+    pub holder: Address,
+    pub amount: i128,
+}
+
+pub(crate) fn holder_credit_paid_out(env: &Env, holder: Address, amount: i128) {
+    let topics = (Symbol::new(env, "holder_credit_payout"), EVENT_VERSION, holder.clone());
+
+    env.events().publish(
+        topics,
+        HolderCreditPayoutEvent { holder, amount }
+    );
+}
@@ -0,0 +1,84 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct InsurancePremiumEvent {
+    pub renter: Address,
+    pub owner: Address,
+    pub rental_id: u32,
+    pub premium: i128,
+}
+
+pub(crate) fn premium_collected(
+    env: &Env,
+    renter: Address,
+    owner: Address,
+    rental_id: u32,
+    premium: i128
+) {
+    let topics = (Symbol::new(env, "insurance_premium"), EVENT_VERSION, renter.clone(), owner.clone());
+
+    env.events().publish(
+        topics,
+        InsurancePremiumEvent { renter, owner, rental_id, premium }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ClaimFiledEvent {
+    pub owner: Address,
+    pub renter: Address,
+    pub rental_id: u32,
+    pub amount: i128,
+}
+
+pub(crate) fn claim_filed(
+    env: &Env,
+    owner: Address,
+    renter: Address,
+    rental_id: u32,
+    amount: i128
+) {
+    let topics = (Symbol::new(env, "claim_filed"), EVENT_VERSION, rental_id);
+
+    env.events().publish(
+        topics,
+        ClaimFiledEvent { owner, renter, rental_id, amount }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ClaimApprovedEvent {
+    pub rental_id: u32,
+    pub approver: Address,
+    pub paid: i128,
+}
+
+pub(crate) fn claim_approved(env: &Env, rental_id: u32, approver: Address, paid: i128) {
+    let topics = (Symbol::new(env, "claim_approved"), EVENT_VERSION, rental_id);
+
+    env.events().publish(
+        topics,
+        ClaimApprovedEvent { rental_id, approver, paid }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ClaimRejectedEvent {
+    pub rental_id: u32,
+    pub approver: Address,
+}
+
+pub(crate) fn claim_rejected(env: &Env, rental_id: u32, approver: Address) {
+    let topics = (Symbol::new(env, "claim_rejected"), EVENT_VERSION, rental_id);
+
+    env.events().publish(
+        topics,
+        ClaimRejectedEvent { rental_id, approver }
+    );
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LoyaltyMintedEvent {
+    pub renter: Address,
+    pub points: i128,
+}
+
+pub(crate) fn loyalty_points_minted(env: &Env, renter: Address, points: i128) {
+    let topics = (Symbol::new(env, "loyalty_minted"), EVENT_VERSION, renter.clone());
+
+    env.events().publish(
+        topics,
+        LoyaltyMintedEvent { renter, points }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LoyaltyRedeemedEvent {
+    pub renter: Address,
+    pub points: i128,
+    pub discount: i128,
+}
+
+pub(crate) fn loyalty_points_redeemed(env: &Env, renter: Address, points: i128, discount: i128) {
+    let topics = (Symbol::new(env, "loyalty_redeemed"), EVENT_VERSION, renter.clone());
+
+    env.events().publish(
+        topics,
+        LoyaltyRedeemedEvent { renter, points, discount }
+    );
+}
@@ -1,6 +1,28 @@
+pub const EVENT_VERSION: u32 = 1; // va en los topics, subirlo si cambia algun payload
+
 pub mod contract;
 pub mod remove_car;
 pub mod payout_owner;
 pub mod payout_admin;
 pub mod rental;
-pub mod add_car;
\ No newline at end of file
+pub mod add_car;
+pub mod withdrawal;
+pub mod treasury;
+pub mod payout_address;
+pub mod withdraw_all;
+pub mod pricing;
+pub mod coupon;
+pub mod referral;
+pub mod loyalty;
+pub mod co_owner;
+pub mod insurance;
+pub mod commission;
+pub mod balance;
+pub mod zone;
+pub mod category;
+pub mod usage;
+pub mod condition;
+pub mod device;
+pub mod pickup;
+pub mod driver;
+pub mod holder_credit;
\ No newline at end of file
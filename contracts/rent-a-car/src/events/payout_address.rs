@@ -0,0 +1,25 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PayoutAddressChangedEvent {
+    pub owner: Address,
+    pub car_id: u32,
+    pub payout_address: Address,
+}
+
+pub(crate) fn payout_address_changed(
+    env: &Env,
+    owner: Address,
+    car_id: u32,
+    payout_address: Address
+) {
+    let topics = (Symbol::new(env, "payout_address_changed"), EVENT_VERSION, owner.clone());
+
+    env.events().publish(
+        topics,
+        PayoutAddressChangedEvent { owner, car_id, payout_address }
+    );
+}
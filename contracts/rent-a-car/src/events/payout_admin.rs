@@ -1,10 +1,19 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
 
-pub(crate) fn payout(env: &Env, admin: Address, amount: i128) {
-    let topics = (Symbol::new(env, "payout_commissions"), admin.clone());
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CommissionPayoutEvent {
+    pub to: Address,
+    pub amount: i128,
+}
+
+pub(crate) fn payout(env: &Env, to: Address, amount: i128) {
+    let topics = (Symbol::new(env, "payout_commissions"), EVENT_VERSION, to.clone());
 
     env.events().publish(
         topics,
-        amount
+        CommissionPayoutEvent { to, amount }
     );
-}
\ No newline at end of file
+}
@@ -1,10 +1,21 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
 
-pub(crate) fn payout(env: &Env, owner: Address, amount: i128) {
-    let topics = (Symbol::new(env, "payout"), owner.clone());
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct OwnerPayoutEvent {
+    pub owner: Address,
+    pub car_id: u32,
+    pub to: Address,
+    pub amount: i128,
+}
+
+pub(crate) fn payout(env: &Env, owner: Address, car_id: u32, to: Address, amount: i128) {
+    let topics = (Symbol::new(env, "payout"), EVENT_VERSION, owner.clone());
 
     env.events().publish(
         topics,
-        amount
+        OwnerPayoutEvent { owner, car_id, to, amount }
     );
-}
\ No newline at end of file
+}
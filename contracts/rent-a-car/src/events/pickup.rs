@@ -0,0 +1,39 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PickupCodeCommittedEvent {
+    pub renter: Address,
+    pub rental_id: u32,
+    pub code_hash: BytesN<32>,
+}
+
+pub(crate) fn pickup_code_committed(env: &Env, renter: Address, rental_id: u32, code_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "pickup_code_committed"), EVENT_VERSION, rental_id);
+
+    env.events().publish(
+        topics,
+        PickupCodeCommittedEvent { renter, rental_id, code_hash }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PickupConfirmedEvent {
+    pub renter: Address,
+    pub owner: Address,
+    pub rental_id: u32,
+    pub started_at: u64,
+    pub released_credit: i128,
+}
+
+pub(crate) fn pickup_confirmed(env: &Env, renter: Address, owner: Address, rental_id: u32, started_at: u64, released_credit: i128) {
+    let topics = (Symbol::new(env, "pickup_confirmed"), EVENT_VERSION, rental_id);
+
+    env.events().publish(
+        topics,
+        PickupConfirmedEvent { renter, owner, rental_id, started_at, released_credit }
+    );
+}
@@ -0,0 +1,43 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::storage::structs::pricing::{DiscountTier, PricingRules};
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PricingRulesUpdatedEvent {
+    pub owner: Address,
+    pub car_id: u32,
+    pub rules: PricingRules,
+}
+
+pub(crate) fn pricing_rules_updated(env: &Env, owner: Address, car_id: u32, rules: PricingRules) {
+    let topics = (Symbol::new(env, "pricing_rules_updated"), EVENT_VERSION, owner.clone());
+
+    env.events().publish(
+        topics,
+        PricingRulesUpdatedEvent { owner, car_id, rules }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DiscountTiersUpdatedEvent {
+    pub owner: Address,
+    pub car_id: u32,
+    pub tiers: Vec<DiscountTier>,
+}
+
+pub(crate) fn discount_tiers_updated(
+    env: &Env,
+    owner: Address,
+    car_id: u32,
+    tiers: Vec<DiscountTier>
+) {
+    let topics = (Symbol::new(env, "discount_tiers_updated"), EVENT_VERSION, owner.clone());
+
+    env.events().publish(
+        topics,
+        DiscountTiersUpdatedEvent { owner, car_id, tiers }
+    );
+}
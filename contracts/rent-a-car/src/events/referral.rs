@@ -0,0 +1,57 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ReferrerRegisteredEvent {
+    pub renter: Address,
+    pub referrer: Address,
+}
+
+pub(crate) fn referrer_registered(env: &Env, renter: Address, referrer: Address) {
+    let topics = (Symbol::new(env, "referrer_registered"), EVENT_VERSION, renter.clone());
+
+    env.events().publish(
+        topics,
+        ReferrerRegisteredEvent { renter, referrer }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ReferralRewardEvent {
+    pub referrer: Address,
+    pub renter: Address,
+    pub reward: i128,
+}
+
+pub(crate) fn referral_reward_accrued(
+    env: &Env,
+    referrer: Address,
+    renter: Address,
+    reward: i128
+) {
+    let topics = (Symbol::new(env, "referral_reward"), EVENT_VERSION, referrer.clone(), renter.clone());
+
+    env.events().publish(
+        topics,
+        ReferralRewardEvent { referrer, renter, reward }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ReferralClaimedEvent {
+    pub referrer: Address,
+    pub amount: i128,
+}
+
+pub(crate) fn referral_rewards_claimed(env: &Env, referrer: Address, amount: i128) {
+    let topics = (Symbol::new(env, "referral_claimed"), EVENT_VERSION, referrer.clone());
+
+    env.events().publish(
+        topics,
+        ReferralClaimedEvent { referrer, amount }
+    );
+}
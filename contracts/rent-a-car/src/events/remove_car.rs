@@ -1,10 +1,19 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
 
-pub(crate) fn car_removed(env: &Env, owner: Address) {
-    let topics = (Symbol::new(env, "car_removed"), owner.clone());
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CarRemovedEvent {
+    pub owner: Address,
+    pub car_id: u32,
+}
+
+pub(crate) fn car_removed(env: &Env, owner: Address, car_id: u32) {
+    let topics = (Symbol::new(env, "car_removed"), EVENT_VERSION, owner.clone());
 
     env.events().publish(
         topics,
-        ()
+        CarRemovedEvent { owner, car_id }
     );
-}
\ No newline at end of file
+}
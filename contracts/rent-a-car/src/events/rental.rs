@@ -1,16 +1,59 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RentedEvent {
+    pub renter: Address,
+    pub owner: Address,
+    pub car_id: u32,
+    pub rental_id: u32,
+    pub total_days: u32,
+    pub amount: i128,
+    pub discount: i128,
+}
 
 pub(crate) fn rented(
     env: &Env,
     renter: Address,
     owner: Address,
+    car_id: u32,
+    rental_id: u32,
     total_days: u32,
-    amount: i128
+    amount: i128,
+    discount: i128
+) {
+    let topics = (Symbol::new(env, "rented"), EVENT_VERSION, renter.clone(), owner.clone());
+
+    env.events().publish(
+        topics,
+        RentedEvent { renter, owner, car_id, rental_id, total_days, amount, discount }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RentalCompletedEvent {
+    pub renter: Address,
+    pub owner: Address,
+    pub car_id: u32,
+    pub rental_id: u32,
+    pub loyalty_points: i128,
+}
+
+pub(crate) fn rental_completed(
+    env: &Env,
+    renter: Address,
+    owner: Address,
+    car_id: u32,
+    rental_id: u32,
+    loyalty_points: i128
 ) {
-    let topics = (Symbol::new(env, "rented"), renter.clone(), owner.clone());
+    let topics = (Symbol::new(env, "rental_completed"), EVENT_VERSION, renter.clone(), owner.clone());
 
     env.events().publish(
         topics,
-        (total_days, amount)
+        RentalCompletedEvent { renter, owner, car_id, rental_id, loyalty_points }
     );
-}
\ No newline at end of file
+}
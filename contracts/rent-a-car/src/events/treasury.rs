@@ -0,0 +1,19 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TreasuryChangedEvent {
+    pub admin: Address,
+    pub treasury: Address,
+}
+
+pub(crate) fn treasury_changed(env: &Env, admin: Address, treasury: Address) {
+    let topics = (Symbol::new(env, "treasury_changed"), EVENT_VERSION, admin.clone());
+
+    env.events().publish(
+        topics,
+        TreasuryChangedEvent { admin, treasury }
+    );
+}
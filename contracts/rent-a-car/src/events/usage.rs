@@ -0,0 +1,88 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+use crate::storage::{
+    structs::usage::{UsageFees, UsageReading},
+    types::reading_kind::ReadingKind,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct UsageFeesUpdatedEvent {
+    pub owner: Address,
+    pub car_id: u32,
+    pub fees: UsageFees,
+}
+
+pub(crate) fn usage_fees_updated(env: &Env, owner: Address, car_id: u32, fees: UsageFees) {
+    let topics = (Symbol::new(env, "usage_fees_updated"), EVENT_VERSION, owner.clone());
+
+    env.events().publish(
+        topics,
+        UsageFeesUpdatedEvent { owner, car_id, fees }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct UsageReadingEvent {
+    pub rental_id: u32,
+    pub kind: ReadingKind,
+    pub reading: UsageReading,
+}
+
+pub(crate) fn usage_reading_recorded(env: &Env, rental_id: u32, kind: ReadingKind, reading: UsageReading) {
+    let topics = (Symbol::new(env, "usage_reading"), EVENT_VERSION, rental_id);
+
+    env.events().publish(
+        topics,
+        UsageReadingEvent { rental_id, kind, reading }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct UsageChargedEvent {
+    pub renter: Address,
+    pub rental_id: u32,
+    pub extra_km: u32,
+    pub km_charge: i128,
+    pub fuel_charge: i128,
+    pub from_deposit: i128, // parte cubierta con el deposito
+    pub owed: i128, // resto que queda como deuda del renter
+}
+
+pub(crate) fn usage_charged(
+    env: &Env,
+    renter: Address,
+    rental_id: u32,
+    extra_km: u32,
+    km_charge: i128,
+    fuel_charge: i128,
+    from_deposit: i128,
+) {
+    let topics = (Symbol::new(env, "usage_charged"), EVENT_VERSION, rental_id);
+    let owed = km_charge + fuel_charge - from_deposit;
+
+    env.events().publish(
+        topics,
+        UsageChargedEvent { renter, rental_id, extra_km, km_charge, fuel_charge, from_deposit, owed }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct UsageDebtSettledEvent {
+    pub renter: Address,
+    pub owner: Address,
+    pub amount: i128,
+}
+
+pub(crate) fn usage_debt_settled(env: &Env, renter: Address, owner: Address, amount: i128) {
+    let topics = (Symbol::new(env, "usage_debt_settled"), EVENT_VERSION, renter.clone());
+
+    env.events().publish(
+        topics,
+        UsageDebtSettledEvent { renter, owner, amount }
+    );
+}
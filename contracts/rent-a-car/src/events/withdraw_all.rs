@@ -0,0 +1,37 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct WithdrewAllEvent {
+    pub holder: Address,
+    pub cars: u32,
+    pub total: i128,
+}
+
+pub(crate) fn withdrew_all(env: &Env, holder: Address, cars: u32, total: i128) {
+    let topics = (Symbol::new(env, "withdraw_all"), EVENT_VERSION, holder.clone());
+
+    env.events().publish(
+        topics,
+        WithdrewAllEvent { holder, cars, total }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct BatchPayoutEvent {
+    pub caller: Address,
+    pub owners: u32,
+    pub total: i128,
+}
+
+pub(crate) fn batch_paid_out(env: &Env, caller: Address, owners: u32, total: i128) {
+    let topics = (Symbol::new(env, "batch_payout"), EVENT_VERSION, caller.clone());
+
+    env.events().publish(
+        topics,
+        BatchPayoutEvent { caller, owners, total }
+    );
+}
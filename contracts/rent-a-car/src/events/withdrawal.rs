@@ -0,0 +1,77 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct WithdrawalProposedEvent {
+    pub id: u32,
+    pub proposer: Address,
+    pub amount: i128,
+    pub expires_at: u64,
+}
+
+pub(crate) fn withdrawal_proposed(
+    env: &Env,
+    id: u32,
+    proposer: Address,
+    amount: i128,
+    expires_at: u64
+) {
+    let topics = (Symbol::new(env, "withdrawal_proposed"), EVENT_VERSION, id);
+
+    env.events().publish(
+        topics,
+        WithdrawalProposedEvent { id, proposer, amount, expires_at }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct WithdrawalApprovedEvent {
+    pub id: u32,
+    pub approver: Address,
+    pub approvals: u32,
+}
+
+pub(crate) fn withdrawal_approved(env: &Env, id: u32, approver: Address, approvals: u32) {
+    let topics = (Symbol::new(env, "withdrawal_approved"), EVENT_VERSION, id);
+
+    env.events().publish(
+        topics,
+        WithdrawalApprovedEvent { id, approver, approvals }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct WithdrawalExecutedEvent {
+    pub id: u32,
+    pub to: Address,
+    pub amount: i128,
+}
+
+pub(crate) fn withdrawal_executed(env: &Env, id: u32, to: Address, amount: i128) {
+    let topics = (Symbol::new(env, "withdrawal_executed"), EVENT_VERSION, id);
+
+    env.events().publish(
+        topics,
+        WithdrawalExecutedEvent { id, to, amount }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct WithdrawalCancelledEvent {
+    pub id: u32,
+    pub caller: Address,
+}
+
+pub(crate) fn withdrawal_cancelled(env: &Env, id: u32, caller: Address) {
+    let topics = (Symbol::new(env, "withdrawal_cancelled"), EVENT_VERSION, id);
+
+    env.events().publish(
+        topics,
+        WithdrawalCancelledEvent { id, caller }
+    );
+}
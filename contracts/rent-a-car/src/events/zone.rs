@@ -0,0 +1,53 @@
+use soroban_sdk::{contracttype, Address, Env, String, Symbol};
+
+use crate::events::EVENT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ZoneUpdatedEvent {
+    pub zone_id: u32,
+    pub name: String,
+    pub geohash_prefix: String,
+}
+
+pub(crate) fn zone_updated(env: &Env, zone_id: u32, name: String, geohash_prefix: String) {
+    let topics = (Symbol::new(env, "zone_updated"), EVENT_VERSION, zone_id);
+
+    env.events().publish(
+        topics,
+        ZoneUpdatedEvent { zone_id, name, geohash_prefix }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ZoneRemovedEvent {
+    pub zone_id: u32,
+}
+
+pub(crate) fn zone_removed(env: &Env, zone_id: u32) {
+    let topics = (Symbol::new(env, "zone_removed"), EVENT_VERSION, zone_id);
+
+    env.events().publish(
+        topics,
+        ZoneRemovedEvent { zone_id }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CarLocationChangedEvent {
+    pub owner: Address,
+    pub car_id: u32,
+    pub zone_id: u32,
+    pub pickup_point: String,
+}
+
+pub(crate) fn car_location_changed(env: &Env, owner: Address, car_id: u32, zone_id: u32, pickup_point: String) {
+    let topics = (Symbol::new(env, "car_location_changed"), EVENT_VERSION, owner.clone());
+
+    env.events().publish(
+        topics,
+        CarLocationChangedEvent { owner, car_id, zone_id, pickup_point }
+    );
+}
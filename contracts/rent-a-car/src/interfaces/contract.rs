@@ -1,17 +1,113 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec};
 
-use crate::storage::types::{car_status::CarStatus, errors::Error};
+use crate::storage::{
+    structs::{
+        category::CategoryRules,
+        condition::ConditionReport,
+        co_owner::CoOwner,
+        coupon::Coupon,
+        insurance::{InsuranceClaim, InsuranceConfig},
+        loyalty::LoyaltyConfig,
+        pricing::{DiscountTier, PricingRules, SeasonalRate},
+        usage::{UsageFees, UsageReading},
+        withdrawal::{WithdrawalPolicy, WithdrawalProposal},
+        zone::{CarLocation, Zone},
+    },
+    types::{
+        car_category::CarCategory, car_status::CarStatus, coupon_discount::CouponDiscount, device_action::DeviceAction,
+        errors::Error, reading_kind::ReadingKind, role::Role,
+    },
+};
 
 pub trait RentACarContractTrait {
     fn __constructor(env: &Env, admin: Address, token: Address) -> Result<(), Error>; // () significa que termine ejecucion
     // fn initialize(env: &Env, admin: Address, token: Address);
-    fn get_admin(env: &Env) -> Address;
-    fn add_car(env: &Env, owner: Address, price_per_day: i128) -> Result<(), Error>;
+    fn get_admin(env: &Env) -> Result<Address, Error>;
+    fn grant_role(env: &Env, caller: Address, account: Address, role: Role) -> Result<(), Error>;
+    fn revoke_role(env: &Env, caller: Address, account: Address, role: Role) -> Result<(), Error>;
+    fn has_role(env: &Env, account: Address, role: Role) -> bool;
+    fn add_car(env: &Env, caller: Address, owner: Address, price_per_day: i128, category: CarCategory, location: Option<CarLocation>) -> Result<(), Error>;
     fn get_car_status(env: &Env, owner: Address) -> Result<CarStatus, Error>;
     fn get_available_withdraw_payowner(env: &Env, owner: Address) -> Result<i128, Error>;
-    fn get_admin_commission(env: &Env) -> i128;
-    fn rental(env: &Env, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128) -> Result<(), Error>;
-    fn remove_car(env: &Env, owner: Address) -> Result<(), Error>;
+    fn get_admin_commission(env: &Env) -> Result<i128, Error>;
+    fn set_pricing_rules(env: &Env, owner: Address, weekday_multipliers_bps: Vec<u32>, seasonal_rates: Vec<SeasonalRate>) -> Result<(), Error>;
+    fn get_pricing_rules(env: &Env, owner: Address) -> Result<PricingRules, Error>;
+    fn set_discount_tiers(env: &Env, owner: Address, tiers: Vec<DiscountTier>) -> Result<(), Error>;
+    fn get_discount_tiers(env: &Env, owner: Address) -> Result<Vec<DiscountTier>, Error>;
+    fn quote_rental(env: &Env, owner: Address, total_days_to_rent: u32, start_at: u64) -> Result<i128, Error>;
+    fn create_coupon(env: &Env, caller: Address, code_hash: BytesN<32>, discount: CouponDiscount, max_uses: u32, per_address_limit: u32, expires_at: u64) -> Result<(), Error>;
+    fn get_coupon(env: &Env, code_hash: BytesN<32>) -> Result<Coupon, Error>;
+    fn register_referrer(env: &Env, renter: Address, referrer: Address) -> Result<(), Error>;
+    fn get_referrer(env: &Env, renter: Address) -> Result<Address, Error>;
+    fn set_referral_share(env: &Env, caller: Address, share_bps: u32) -> Result<(), Error>;
+    fn get_referral_share(env: &Env) -> u32;
+    fn get_referral_balance(env: &Env, referrer: Address) -> i128;
+    fn claim_referral_rewards(env: &Env, referrer: Address) -> Result<i128, Error>;
+    fn set_loyalty_config(env: &Env, caller: Address, token: Address, earn_rate_bps: u32, redeem_rate_bps: u32) -> Result<(), Error>;
+    fn get_loyalty_config(env: &Env) -> Result<LoyaltyConfig, Error>;
+    fn set_insurance_config(env: &Env, caller: Address, premium_bps: u32, max_claim_amount: i128, claim_window_secs: u64) -> Result<(), Error>;
+    fn get_insurance_config(env: &Env) -> Result<InsuranceConfig, Error>;
+    fn get_insurance_pool(env: &Env) -> i128;
+    fn file_insurance_claim(env: &Env, owner: Address, rental_id: u32, amount: i128) -> Result<(), Error>;
+    fn approve_insurance_claim(env: &Env, caller: Address, rental_id: u32, amount: i128) -> Result<(), Error>;
+    fn reject_insurance_claim(env: &Env, caller: Address, rental_id: u32) -> Result<(), Error>;
+    fn get_insurance_claim(env: &Env, rental_id: u32) -> Result<InsuranceClaim, Error>;
+    fn rental(env: &Env, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128, promo_code: Option<Bytes>, loyalty_points: i128, start_at: u64) -> Result<(), Error>;
+    fn complete_rental(env: &Env, owner: Address, renter: Address) -> Result<(), Error>;
+    fn set_usage_fees(env: &Env, owner: Address, included_km_per_day: u32, price_per_extra_km: i128, refuel_fee_per_pct: i128) -> Result<(), Error>;
+    fn get_usage_fees(env: &Env, owner: Address) -> Result<UsageFees, Error>;
+    fn submit_usage_reading(env: &Env, renter: Address, owner: Address, kind: ReadingKind, odometer_km: u32, fuel_level_pct: u32) -> Result<(), Error>;
+    fn confirm_usage_reading(env: &Env, owner: Address, renter: Address, kind: ReadingKind) -> Result<(), Error>;
+    fn record_return_reading(env: &Env, owner: Address, renter: Address, odometer_km: u32, fuel_level_pct: u32) -> Result<(), Error>;
+    fn get_usage_reading(env: &Env, renter: Address, owner: Address, kind: ReadingKind) -> Result<UsageReading, Error>;
+    fn get_usage_debt(env: &Env, renter: Address, owner: Address) -> i128;
+    fn settle_usage_debt(env: &Env, renter: Address, owner: Address) -> Result<i128, Error>;
+    fn submit_condition_report(env: &Env, renter: Address, owner: Address, kind: ReadingKind, evidence: Vec<BytesN<32>>) -> Result<(), Error>;
+    fn get_condition_report(env: &Env, rental_id: u32, kind: ReadingKind) -> Result<ConditionReport, Error>;
+    fn set_car_device(env: &Env, owner: Address, public_key: BytesN<32>) -> Result<(), Error>;
+    fn get_car_device(env: &Env, owner: Address) -> Result<BytesN<32>, Error>;
+    fn set_pickup_code_required(env: &Env, owner: Address, required: bool) -> Result<(), Error>;
+    fn commit_pickup_code(env: &Env, owner: Address, renter: Address, code_hash: BytesN<32>) -> Result<(), Error>;
+    fn confirm_pickup(env: &Env, renter: Address, owner: Address, code: Bytes) -> Result<(), Error>;
+    fn set_rental_limits(env: &Env, owner: Address, min_days: u32, max_days: u32, min_notice_secs: u64) -> Result<(), Error>;
+    fn set_driver_fee(env: &Env, owner: Address, fee_per_day: i128) -> Result<(), Error>;
+    fn add_rental_driver(env: &Env, renter: Address, owner: Address, driver: Address) -> Result<(), Error>;
+    fn accept_rental_driver(env: &Env, driver: Address, renter: Address, owner: Address) -> Result<(), Error>;
+    fn get_rental_drivers(env: &Env, renter: Address, owner: Address) -> Result<Vec<Address>, Error>;
+    fn report_device_event(env: &Env, owner: Address, renter: Address, action: DeviceAction, timestamp: u64, nonce: u64, signature: BytesN<64>) -> Result<(), Error>;
+    fn remove_car(env: &Env, caller: Address, owner: Address) -> Result<(), Error>;
     fn payout_owner(env: &Env, owner: Address, amount: i128) -> Result<(), Error>;
+    fn withdraw_all(env: &Env, holder: Address) -> Result<i128, Error>;
+    fn batch_payout(env: &Env, caller: Address, owners: Vec<Address>) -> Result<i128, Error>;
+    fn set_payout_address(env: &Env, owner: Address, payout_address: Address) -> Result<(), Error>;
+    fn update_price(env: &Env, owner: Address, price_per_day: i128) -> Result<(), Error>;
+    fn get_car_token_id(env: &Env, owner: Address) -> Result<u32, Error>;
+    fn get_car_holder(env: &Env, owner: Address) -> Result<Address, Error>;
+    fn get_holder_credit(env: &Env, holder: Address) -> i128;
+    fn withdraw_holder_credit(env: &Env, holder: Address, amount: i128) -> Result<(), Error>;
+    fn set_co_owners(env: &Env, owner: Address, co_owners: Vec<CoOwner>) -> Result<(), Error>;
+    fn get_co_owners(env: &Env, owner: Address) -> Vec<CoOwner>;
+    fn get_co_owner_balance(env: &Env, owner: Address, co_owner: Address) -> i128;
+    fn withdraw_co_owner_balance(env: &Env, owner: Address, co_owner: Address) -> Result<i128, Error>;
+    fn set_zone(env: &Env, caller: Address, zone_id: u32, name: String, geohash_prefix: String) -> Result<(), Error>;
+    fn remove_zone(env: &Env, caller: Address, zone_id: u32) -> Result<(), Error>;
+    fn get_zone(env: &Env, zone_id: u32) -> Result<Zone, Error>;
+    fn set_car_location(env: &Env, caller: Address, owner: Address, zone_id: u32, pickup_point: String) -> Result<(), Error>;
+    fn get_car_location(env: &Env, owner: Address) -> Result<CarLocation, Error>;
+    fn list_cars_in_zone(env: &Env, zone_id: u32, start: u32, limit: u32) -> Result<Vec<Address>, Error>;
+    fn set_category_rules(env: &Env, caller: Address, category: CarCategory, min_verification_level: u32, max_rental_days: u32, deposit_floor: i128) -> Result<(), Error>;
+    fn get_category_rules(env: &Env, category: CarCategory) -> Result<CategoryRules, Error>;
+    fn set_renter_verification(env: &Env, caller: Address, renter: Address, level: u32) -> Result<(), Error>;
+    fn get_renter_verification(env: &Env, renter: Address) -> u32;
+    fn list_cars_in_category(env: &Env, category: CarCategory, start: u32, limit: u32) -> Vec<Address>;
+    fn get_payout_address(env: &Env, owner: Address) -> Result<Address, Error>;
     fn payout_admin(env: &Env, admin: Address, amount: i128) -> Result<(), Error>;
+    fn set_treasury(env: &Env, caller: Address, treasury: Address) -> Result<(), Error>;
+    fn get_treasury(env: &Env) -> Result<Address, Error>;
+    fn set_withdrawal_policy(env: &Env, caller: Address, approvers: Vec<Address>, required_approvals: u32, amount_threshold: i128, proposal_ttl: u64, period_secs: u64) -> Result<(), Error>;
+    fn get_withdrawal_policy(env: &Env) -> Result<WithdrawalPolicy, Error>;
+    fn propose_withdrawal(env: &Env, proposer: Address, amount: i128) -> Result<u32, Error>;
+    fn approve_withdrawal(env: &Env, approver: Address, id: u32) -> Result<(), Error>;
+    fn cancel_withdrawal(env: &Env, caller: Address, id: u32) -> Result<(), Error>;
+    fn get_withdrawal(env: &Env, id: u32) -> Result<WithdrawalProposal, Error>;
 }
\ No newline at end of file
@@ -0,0 +1,8 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+// funciones del token de lealtad (contracts/loyalty-token) que llama este contrato
+#[contractclient(name = "LoyaltyTokenClient")]
+pub trait LoyaltyTokenInterface {
+    fn mint(env: Env, to: Address, amount: i128);
+    fn burn(env: Env, from: Address, amount: i128);
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use soroban_sdk::{Address, Env};
+
+use crate::methods::listing::listing::{add_to_index, remove_from_index};
+use crate::storage::{
+    category::{
+        read_category_cars, read_category_rules, read_renter_verification,
+        write_category_cars,
+    },
+    structs::category::CategoryRules,
+    types::{car_category::CarCategory, errors::Error},
+};
+
+pub fn validate_category_rules(rules: &CategoryRules) -> Result<(), Error> {
+    if rules.deposit_floor < 0 {
+        return Err(Error::InvalidCategoryRules);
+    }
+
+    Ok(())
+}
+
+// valida la renta contra las reglas de la categoria y devuelve el deposito a retener
+pub fn enforce_category_rules(
+    env: &Env,
+    renter: &Address,
+    category: &CarCategory,
+    total_days_to_rent: u32,
+) -> Result<i128, Error> {
+    let Ok(rules) = read_category_rules(env, category) else {
+        return Ok(0);
+    };
+
+    if read_renter_verification(env, renter) < rules.min_verification_level {
+        return Err(Error::RenterVerificationTooLow);
+    }
+
+    if rules.max_rental_days > 0 && total_days_to_rent > rules.max_rental_days {
+        return Err(Error::RentalTooLong);
+    }
+
+    Ok(rules.deposit_floor)
+}
+
+pub fn add_to_category_index(env: &Env, category: &CarCategory, owner: &Address) {
+    let mut cars = read_category_cars(env, category);
+
+    if add_to_index(&mut cars, owner) {
+        write_category_cars(env, category, &cars);
+    }
+}
+
+pub fn remove_from_category_index(env: &Env, category: &CarCategory, owner: &Address) {
+    let mut cars = read_category_cars(env, category);
+
+    if remove_from_index(&mut cars, owner) {
+        write_category_cars(env, category, &cars);
+    }
+}
@@ -0,0 +1,56 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{
+    co_owner::{read_co_owner_balance, read_co_owners, write_co_owner_balance},
+    structs::co_owner::CoOwner,
+    types::errors::Error,
+};
+
+const TOTAL_SHARES_BPS: u32 = 10_000;
+
+pub fn validate_co_owners(co_owners: &Vec<CoOwner>) -> Result<(), Error> {
+    if co_owners.is_empty() {
+        return Err(Error::InvalidCoOwnerShares);
+    }
+
+    let mut total: u32 = 0;
+
+    for (i, co_owner) in co_owners.iter().enumerate() {
+        if co_owner.share_bps == 0 || co_owner.share_bps > TOTAL_SHARES_BPS {
+            return Err(Error::InvalidCoOwnerShares);
+        }
+
+        // un co-owner no puede aparecer dos veces en la tabla
+        if co_owners.iter().skip(i + 1).any(|other| other.address == co_owner.address) {
+            return Err(Error::InvalidCoOwnerShares);
+        }
+
+        total += co_owner.share_bps;
+    }
+
+    if total != TOTAL_SHARES_BPS {
+        return Err(Error::InvalidCoOwnerShares);
+    }
+
+    Ok(())
+}
+
+// reparte el credito del owner segun la tabla de shares, el residuo del redondeo
+// se lo lleva el primer co-owner para que no quede nada sin asignar
+pub fn split_owner_credit(env: &Env, owner: &Address, credit: i128) {
+    let co_owners = read_co_owners(env, owner);
+    let mut remaining = credit;
+
+    for co_owner in co_owners.iter().skip(1) {
+        let share = credit * co_owner.share_bps as i128 / TOTAL_SHARES_BPS as i128;
+        let balance = read_co_owner_balance(env, owner, &co_owner.address);
+
+        write_co_owner_balance(env, owner, &co_owner.address, balance + share);
+        remaining -= share;
+    }
+
+    if let Some(first) = co_owners.first() {
+        let balance = read_co_owner_balance(env, owner, &first.address);
+        write_co_owner_balance(env, owner, &first.address, balance + remaining);
+    }
+}
@@ -0,0 +1 @@
+pub mod co_owner;
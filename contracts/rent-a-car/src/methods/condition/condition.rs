@@ -0,0 +1,20 @@
+use soroban_sdk::{BytesN, Vec};
+
+use crate::storage::types::errors::Error;
+
+const MAX_EVIDENCE: u32 = 20;
+
+pub fn validate_evidence(evidence: &Vec<BytesN<32>>) -> Result<(), Error> {
+    if evidence.is_empty() || evidence.len() > MAX_EVIDENCE {
+        return Err(Error::InvalidConditionReport);
+    }
+
+    // la misma foto no cuenta dos veces
+    for (i, hash) in evidence.iter().enumerate() {
+        if evidence.iter().skip(i + 1).any(|other| other == hash) {
+            return Err(Error::InvalidConditionReport);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+use crate::storage::{
+    coupon::{read_coupon, read_coupon_usage, write_coupon, write_coupon_usage},
+    types::{coupon_discount::CouponDiscount, errors::Error},
+};
+
+// valida el codigo contra su hash, consume un uso y devuelve el descuento para `price`
+pub fn redeem_coupon(env: &Env, renter: &Address, code: &Bytes, price: i128) -> Result<i128, Error> {
+    let code_hash: BytesN<32> = env.crypto().sha256(code).into();
+
+    let mut coupon = read_coupon(env, &code_hash)?;
+
+    if env.ledger().timestamp() >= coupon.expires_at {
+        return Err(Error::CouponExpired);
+    }
+
+    if coupon.uses_remaining == 0 {
+        return Err(Error::CouponExhausted);
+    }
+
+    let usage = read_coupon_usage(env, &code_hash, renter);
+
+    if usage >= coupon.per_address_limit {
+        return Err(Error::CouponLimitReached);
+    }
+
+    let discount = match coupon.discount {
+        CouponDiscount::Fixed(value) => value,
+        CouponDiscount::Bps(bps) => price * bps as i128 / 10_000,
+    };
+
+    coupon.uses_remaining -= 1;
+    write_coupon(env, &code_hash, &coupon);
+    write_coupon_usage(env, &code_hash, renter, usage + 1);
+
+    // el descuento nunca puede ser mayor que el precio
+    let discount = discount.min(price);
+
+    crate::events::coupon::coupon_redeemed(env, code_hash, renter.clone(), discount);
+    Ok(discount)
+}
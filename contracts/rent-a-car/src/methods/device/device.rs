@@ -0,0 +1,47 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+use crate::storage::{
+    device::{read_car_device, read_device_nonce, write_device_nonce},
+    types::{device_action::DeviceAction, errors::Error},
+};
+
+// reportes mas viejos que esto se rechazan aunque el nonce sea nuevo
+const MAX_REPORT_AGE_SECS: u64 = 3600;
+
+// mensaje que firma el dispositivo: rental_id | accion | timestamp | nonce, todo big-endian.
+// la accion va en el mensaje para que un unlock firmado no se pueda enviar como lock
+pub fn device_report_message(env: &Env, rental_id: u32, action: &DeviceAction, timestamp: u64, nonce: u64) -> Bytes {
+    let mut message = Bytes::new(env);
+
+    message.extend_from_array(&rental_id.to_be_bytes());
+    message.extend_from_array(&(action.clone() as u32).to_be_bytes());
+    message.extend_from_array(&timestamp.to_be_bytes());
+    message.extend_from_array(&nonce.to_be_bytes());
+
+    message
+}
+
+// verifica la firma del dispositivo y consume el nonce, ed25519_verify aborta si la firma no es valida
+pub fn verify_device_report(
+    env: &Env,
+    owner: &Address,
+    rental_id: u32,
+    action: &DeviceAction,
+    timestamp: u64,
+    nonce: u64,
+    signature: &BytesN<64>,
+) -> Result<(), Error> {
+    let public_key = read_car_device(env, owner)?;
+
+    let now = env.ledger().timestamp();
+
+    if nonce <= read_device_nonce(env, owner) || timestamp > now || now - timestamp > MAX_REPORT_AGE_SECS {
+        return Err(Error::StaleDeviceReport);
+    }
+
+    let message = device_report_message(env, rental_id, action, timestamp, nonce);
+    env.crypto().ed25519_verify(&public_key, &message, signature);
+
+    write_device_nonce(env, owner, nonce);
+    Ok(())
+}
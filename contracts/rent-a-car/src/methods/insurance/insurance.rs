@@ -0,0 +1,12 @@
+use soroban_sdk::Env;
+
+use crate::storage::insurance::read_insurance_config;
+
+// prima que se cobra sobre el precio de la renta, 0 si el seguro no esta configurado
+pub fn compute_insurance_premium(env: &Env, price: i128) -> i128 {
+    let Ok(config) = read_insurance_config(env) else {
+        return 0;
+    };
+
+    price * config.premium_bps as i128 / 10_000
+}
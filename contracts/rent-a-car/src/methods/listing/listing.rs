@@ -0,0 +1,33 @@
+use soroban_sdk::{Address, Env, Vec};
+
+// tope de resultados por pagina en los listados
+const MAX_PAGE_SIZE: u32 = 50;
+
+pub fn paginate(env: &Env, items: &Vec<Address>, start: u32, limit: u32) -> Vec<Address> {
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(items.len());
+
+    if start >= end {
+        return Vec::new(env);
+    }
+
+    items.slice(start..end)
+}
+
+pub fn add_to_index(items: &mut Vec<Address>, owner: &Address) -> bool {
+    if items.contains(owner) {
+        return false;
+    }
+
+    items.push_back(owner.clone());
+    true
+}
+
+pub fn remove_from_index(items: &mut Vec<Address>, owner: &Address) -> bool {
+    match items.first_index_of(owner) {
+        Some(index) => {
+            items.remove(index);
+            true
+        }
+        None => false,
+    }
+}
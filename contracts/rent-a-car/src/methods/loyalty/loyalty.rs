@@ -0,0 +1,42 @@
+use soroban_sdk::{Address, Env};
+
+use crate::interfaces::loyalty_token::LoyaltyTokenClient;
+use crate::storage::{
+    loyalty::read_loyalty_config,
+    types::errors::Error,
+};
+
+// mintea puntos proporcionales a lo pagado, si no hay programa de lealtad no hace nada
+pub fn mint_loyalty_points(env: &Env, renter: &Address, amount_paid: i128) -> i128 {
+    let Ok(config) = read_loyalty_config(env) else {
+        return 0;
+    };
+    let points = amount_paid * config.earn_rate_bps as i128 / 10_000;
+
+    if points <= 0 {
+        return 0;
+    }
+
+    // el contrato tiene que ser el owner del token de lealtad
+    let loyalty_token = LoyaltyTokenClient::new(env, &config.token);
+    loyalty_token.mint(renter, &points);
+
+    crate::events::loyalty::loyalty_points_minted(env, renter.clone(), points);
+    points
+}
+
+// quema los puntos del renter y devuelve el descuento que valen
+pub fn redeem_loyalty_points(env: &Env, renter: &Address, points: i128, max_discount: i128) -> Result<i128, Error> {
+    let config = read_loyalty_config(env)?;
+    let discount = points * config.redeem_rate_bps as i128 / 10_000;
+
+    if discount > max_discount {
+        return Err(Error::LoyaltyDiscountExceedsPrice);
+    }
+
+    let loyalty_token = LoyaltyTokenClient::new(env, &config.token);
+    loyalty_token.burn(renter, &points);
+
+    crate::events::loyalty::loyalty_points_redeemed(env, renter.clone(), points, discount);
+    Ok(discount)
+}
@@ -1 +1,18 @@
-pub mod token;
\ No newline at end of file
+pub mod token;
+pub mod roles;
+pub mod payout;
+pub mod pricing;
+pub mod coupon;
+pub mod referral;
+pub mod loyalty;
+pub mod nft;
+pub mod co_owner;
+pub mod insurance;
+pub mod zone;
+pub mod listing;
+pub mod category;
+
+pub mod usage;
+pub mod condition;
+pub mod device;
+pub mod pickup;
\ No newline at end of file
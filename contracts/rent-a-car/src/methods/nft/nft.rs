@@ -0,0 +1,102 @@
+use soroban_sdk::{Address, Env};
+use stellar_tokens::non_fungible::Base;
+
+use crate::methods::listing::listing::{add_to_index, remove_from_index};
+use crate::storage::{
+    car::{
+        read_car, read_car_token, read_held_holder_credit, read_holder_cars, read_holder_credit,
+        remove_held_holder_credit, write_car, write_car_token, write_held_holder_credit, write_holder_cars,
+        write_holder_credit,
+    },
+    structs::car::Car,
+    types::car_status::CarStatus,
+};
+
+pub fn mint_car_token(env: &Env, owner: &Address) -> u32 {
+    let token_id = Base::sequential_mint(env, owner);
+
+    write_car_token(env, token_id, owner);
+    add_to_holder_index(env, owner, owner);
+    token_id
+}
+
+pub fn add_to_holder_index(env: &Env, holder: &Address, owner: &Address) {
+    let mut cars = read_holder_cars(env, holder);
+
+    if add_to_index(&mut cars, owner) {
+        write_holder_cars(env, holder, &cars);
+    }
+}
+
+pub fn remove_from_holder_index(env: &Env, holder: &Address, owner: &Address) {
+    let mut cars = read_holder_cars(env, holder);
+
+    if remove_from_index(&mut cars, owner) {
+        write_holder_cars(env, holder, &cars);
+    }
+}
+
+pub fn read_car_holder(env: &Env, car: &Car) -> Address {
+    Base::owner_of(env, car.token_id)
+}
+
+// el holder actual del NFT es quien autoriza las acciones del owner
+pub fn require_car_holder(env: &Env, car: &Car) -> Address {
+    let holder = read_car_holder(env, car);
+    holder.require_auth();
+
+    holder
+}
+
+// un payout_address configurado por el holder anterior no debe seguir recibiendo fondos,
+// y lo que el carro ya habia ganado queda como credito del holder anterior
+pub fn on_car_token_transfer(env: &Env, token_id: u32, from: &Address, to: &Address) {
+    let Ok(owner) = read_car_token(env, token_id) else {
+        return;
+    };
+    let Ok(mut car) = read_car(env, &owner) else {
+        return;
+    };
+
+    let settled = car.available_to_withdraw;
+    let rented = car.car_status == CarStatus::Rented;
+
+    // con una renta en curso el credito queda retenido y se libera en complete_rental
+    if settled > 0 && rented {
+        let mut held = read_held_holder_credit(env, &owner);
+        held.set(from.clone(), held.get(from.clone()).unwrap_or(0) + settled);
+        write_held_holder_credit(env, &owner, &held);
+        car.available_to_withdraw = 0;
+    } else if settled > 0 {
+        write_holder_credit(env, from, read_holder_credit(env, from) + settled);
+        car.available_to_withdraw = 0;
+    }
+
+    car.payout_address = to.clone();
+    write_car(env, &owner, &car);
+
+    remove_from_holder_index(env, from, &owner);
+    add_to_holder_index(env, to, &owner);
+
+    if settled > 0 && !rented {
+        crate::events::holder_credit::holder_credit_settled(env, from.clone(), token_id, settled);
+    }
+
+    crate::events::payout_address::payout_address_changed(env, owner, token_id, to.clone());
+}
+
+// al terminar la renta el credito retenido pasa a los holders que vendieron el carro durante ella
+pub fn release_held_holder_credit(env: &Env, owner: &Address, token_id: u32) {
+    let held = read_held_holder_credit(env, owner);
+
+    if held.is_empty() {
+        return;
+    }
+
+    remove_held_holder_credit(env, owner);
+
+    for (holder, amount) in held.iter() {
+        write_holder_credit(env, &holder, read_holder_credit(env, &holder) + amount);
+        crate::events::holder_credit::holder_credit_settled(env, holder, token_id, amount);
+    }
+}
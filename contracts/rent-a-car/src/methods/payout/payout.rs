@@ -0,0 +1,39 @@
+use soroban_sdk::{Address, Env};
+
+use crate::methods::token::token::token_transfer;
+use crate::storage::{
+    car::{read_car, write_car},
+    contract_balance::{read_contract_balance, write_contract_balance},
+    types::{car_status::CarStatus, errors::Error},
+};
+
+// retira todo lo disponible del carro del owner y devuelve el monto enviado,
+// los carros rentados o sin balance se saltan (devuelve 0)
+pub fn sweep_owner_balance(env: &Env, owner: &Address) -> Result<i128, Error> {
+    let mut car = read_car(env, owner)?;
+
+    if car.car_status == CarStatus::Rented || car.available_to_withdraw <= 0 {
+        return Ok(0);
+    }
+
+    let mut contract_balance = read_contract_balance(env);
+    let amount = car.available_to_withdraw;
+
+    // nunca se paga menos de lo acreditado, el faltante no puede desaparecer del credito
+    if amount > contract_balance {
+        return Err(Error::BalanceNotAvailableForAmountRequested);
+    }
+
+    let previous_balance = contract_balance;
+
+    car.available_to_withdraw -= amount;
+    contract_balance -= amount;
+
+    write_car(env, owner, &car);
+    write_contract_balance(env, &contract_balance);
+
+    token_transfer(env, &env.current_contract_address(), &car.payout_address, &amount)?;
+    crate::events::balance::contract_balance_changed(env, previous_balance, contract_balance);
+    crate::events::payout_owner::payout(env, owner.clone(), car.token_id, car.payout_address.clone(), amount);
+    Ok(amount)
+}
@@ -0,0 +1,49 @@
+use soroban_sdk::{Address, Env};
+
+use crate::methods::co_owner::co_owner::split_owner_credit;
+use crate::storage::{
+    co_owner::has_co_owners,
+    contract_balance::{read_contract_balance, write_contract_balance},
+    structs::{car::Car, rental::Rental},
+};
+
+// marca el inicio real de la renta y pasa el credito pendiente del owner a disponible,
+// junto con el cargo de los conductores que ya aceptaron
+pub fn start_rental(env: &Env, owner: &Address, car: &mut Car, rental: &mut Rental, timestamp: u64) {
+    rental.started_at = Some(timestamp);
+
+    let accepted_driver_fees: i128 = rental.drivers.iter().filter(|d| d.accepted).map(|d| d.fee).sum();
+    release_driver_fees(env, owner, car, accepted_driver_fees);
+
+    if rental.pending_credit == 0 {
+        return;
+    }
+
+    car.pending_to_withdraw -= rental.pending_credit;
+
+    if has_co_owners(env, owner) {
+        split_owner_credit(env, owner, rental.pending_credit);
+    } else {
+        car.available_to_withdraw += rental.pending_credit;
+    }
+
+    rental.pending_credit = 0;
+}
+
+// el cargo por conductor queda retenido como el deposito hasta que la renta arranca,
+// al liberarse va completo al owner y no lleva comision
+pub fn release_driver_fees(env: &Env, owner: &Address, car: &mut Car, fees: i128) {
+    if fees <= 0 {
+        return;
+    }
+
+    if has_co_owners(env, owner) {
+        split_owner_credit(env, owner, fees);
+    } else {
+        car.available_to_withdraw += fees;
+    }
+
+    let previous_balance = read_contract_balance(env);
+    write_contract_balance(env, &(previous_balance + fees));
+    crate::events::balance::contract_balance_changed(env, previous_balance, previous_balance + fees);
+}
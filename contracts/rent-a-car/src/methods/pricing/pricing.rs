@@ -0,0 +1,83 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{
+    pricing::{read_discount_tiers, read_pricing_rules},
+    structs::{car::Car, pricing::{DiscountTier, PricingRules}},
+    types::errors::Error,
+};
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+// el 1 de enero de 1970 fue jueves, con lunes = 0 el jueves es 3
+fn weekday(timestamp: u64) -> u32 {
+    ((timestamp / SECONDS_PER_DAY + 3) % 7) as u32
+}
+
+pub fn validate_pricing_rules(rules: &PricingRules) -> Result<(), Error> {
+    if !rules.weekday_multipliers_bps.is_empty() && rules.weekday_multipliers_bps.len() != 7 {
+        return Err(Error::InvalidPricingRules);
+    }
+
+    if rules.weekday_multipliers_bps.iter().any(|bps| bps == 0) {
+        return Err(Error::InvalidPricingRules);
+    }
+
+    if rules.seasonal_rates.iter().any(|rate| rate.start >= rate.end || rate.multiplier_bps == 0) {
+        return Err(Error::InvalidPricingRules);
+    }
+
+    Ok(())
+}
+
+pub fn validate_discount_tiers(tiers: &Vec<DiscountTier>) -> Result<(), Error> {
+    if tiers.iter().any(|tier| tier.min_days == 0 || tier.discount_bps >= BPS_DENOMINATOR as u32) {
+        return Err(Error::InvalidDiscountTiers);
+    }
+
+    Ok(())
+}
+
+// precio de un solo dia que empieza en `timestamp`
+fn price_for_day(price_per_day: i128, rules: &PricingRules, timestamp: u64) -> i128 {
+    let mut price = price_per_day;
+
+    if let Some(bps) = rules.weekday_multipliers_bps.get(weekday(timestamp)) {
+        price = price * bps as i128 / BPS_DENOMINATOR;
+    }
+
+    if let Some(rate) = rules
+        .seasonal_rates
+        .iter()
+        .find(|rate| timestamp >= rate.start && timestamp < rate.end)
+    {
+        price = price * rate.multiplier_bps as i128 / BPS_DENOMINATOR;
+    }
+
+    price
+}
+
+// los dias se cotizan desde start_at, un start_at en el pasado se cotiza desde ahora
+pub fn compute_rental_price(env: &Env, owner: &Address, car: &Car, total_days_to_rent: u32, start_at: u64) -> i128 {
+    let Some(rules) = read_pricing_rules(env, owner) else {
+        return car.price_per_day * total_days_to_rent as i128;
+    };
+    let start = start_at.max(env.ledger().timestamp());
+
+    (0..total_days_to_rent as u64)
+        .map(|day| price_for_day(car.price_per_day, &rules, start + day * SECONDS_PER_DAY))
+        .sum()
+}
+
+// se usa el tier con el mayor min_days que cumpla la renta
+pub fn compute_long_term_discount(env: &Env, owner: &Address, total_days_to_rent: u32, price: i128) -> i128 {
+    let best_tier = read_discount_tiers(env, owner)
+        .iter()
+        .filter(|tier| total_days_to_rent >= tier.min_days)
+        .max_by_key(|tier| tier.min_days);
+
+    match best_tier {
+        Some(tier) => price * tier.discount_bps as i128 / BPS_DENOMINATOR,
+        None => 0,
+    }
+}
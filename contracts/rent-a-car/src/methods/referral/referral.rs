@@ -0,0 +1,25 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::referral::{
+    read_referral_balance, read_referral_share, read_referrer, write_referral_balance,
+};
+
+// acredita al referidor del renter su parte de la comision y devuelve ese monto
+pub fn accrue_referral_reward(env: &Env, renter: &Address, commission: i128) -> i128 {
+    let Ok(referrer) = read_referrer(env, renter) else {
+        return 0;
+    };
+
+    let reward = commission * read_referral_share(env) as i128 / 10_000;
+
+    if reward <= 0 {
+        return 0;
+    }
+
+    let balance = read_referral_balance(env, &referrer);
+
+    write_referral_balance(env, &referrer, balance + reward);
+
+    crate::events::referral::referral_reward_accrued(env, referrer, renter.clone(), reward);
+    reward
+}
@@ -0,0 +1,35 @@
+use soroban_sdk::{Address, Env};
+use stellar_access::access_control;
+
+use crate::storage::{admin::read_admin, types::{errors::Error, role::Role}};
+
+pub fn has_role(env: &Env, account: &Address, role: &Role) -> bool {
+    access_control::has_role(env, account, &role.to_symbol(env)).is_some()
+}
+
+// el unico admin es el guardado en el constructor, Role::Admin nunca se otorga
+pub fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+    caller.require_auth();
+
+    if *caller != read_admin(env)? {
+        return Err(Error::Unauthorized);
+    }
+
+    Ok(())
+}
+
+// el admin guardado en el constructor siempre pasa, el resto necesita alguno de los roles.
+// un Role::Admin otorgado no cuenta, solo el admin guardado
+pub fn require_role(env: &Env, caller: &Address, roles: &[Role]) -> Result<(), Error> {
+    caller.require_auth();
+
+    if *caller == read_admin(env)? {
+        return Ok(());
+    }
+
+    if roles.iter().any(|role| *role != Role::Admin && has_role(env, caller, role)) {
+        return Ok(());
+    }
+
+    Err(Error::Unauthorized)
+}
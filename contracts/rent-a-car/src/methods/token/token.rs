@@ -3,10 +3,12 @@ use soroban_sdk::{
     Address, Env,
 };
 
-use crate::storage::token::read_token;
+use crate::storage::{token::read_token, types::errors::Error};
 
-pub fn token_transfer(env: &Env, from: &Address, to: &Address, amount: &i128) {
-    let token_address = read_token(env);
+pub fn token_transfer(env: &Env, from: &Address, to: &Address, amount: &i128) -> Result<(), Error> {
+    let token_address = read_token(env)?;
     let token = token::TokenClient::new(env, &token_address);
     token.transfer(from, to, amount);
+
+    Ok(())
 }
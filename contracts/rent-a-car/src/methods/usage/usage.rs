@@ -0,0 +1,58 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    structs::{rental::Rental, usage::{UsageFees, UsageReading}},
+    types::errors::Error,
+    usage::read_usage_fees,
+};
+
+const MAX_FUEL_LEVEL_PCT: u32 = 100;
+
+pub fn validate_usage_fees(fees: &UsageFees) -> Result<(), Error> {
+    if fees.price_per_extra_km < 0 || fees.refuel_fee_per_pct < 0 {
+        return Err(Error::InvalidUsageFees);
+    }
+
+    Ok(())
+}
+
+pub fn validate_reading(reading: &UsageReading, pickup: &Option<UsageReading>) -> Result<(), Error> {
+    if reading.fuel_level_pct > MAX_FUEL_LEVEL_PCT {
+        return Err(Error::InvalidReading);
+    }
+
+    // el odometro no puede retroceder entre la entrega y la devolucion
+    if let Some(pickup) = pickup {
+        if reading.odometer_km < pickup.odometer_km {
+            return Err(Error::InvalidReading);
+        }
+    }
+
+    Ok(())
+}
+
+// cargo extra por km de mas y por devolver con menos combustible,
+// solo aplica si ambas lecturas fueron confirmadas por el owner
+pub fn compute_usage_charge(env: &Env, owner: &Address, rental: &Rental) -> (u32, i128, i128) {
+    let Ok(fees) = read_usage_fees(env, owner) else {
+        return (0, 0, 0);
+    };
+
+    let (Some(pickup), Some(ret)) = (&rental.pickup_reading, &rental.return_reading) else {
+        return (0, 0, 0);
+    };
+
+    if !pickup.confirmed || !ret.confirmed {
+        return (0, 0, 0);
+    }
+
+    let driven_km = ret.odometer_km - pickup.odometer_km;
+    let included_km = fees.included_km_per_day.saturating_mul(rental.total_days_to_rent);
+    let extra_km = driven_km.saturating_sub(included_km);
+    let missing_fuel_pct = pickup.fuel_level_pct.saturating_sub(ret.fuel_level_pct);
+
+    let km_charge = extra_km as i128 * fees.price_per_extra_km;
+    let fuel_charge = missing_fuel_pct as i128 * fees.refuel_fee_per_pct;
+
+    (extra_km, km_charge, fuel_charge)
+}
@@ -0,0 +1,56 @@
+use soroban_sdk::{Address, Env, String};
+
+use crate::methods::listing::listing::{add_to_index, remove_from_index};
+use crate::storage::{
+    types::errors::Error,
+    zone::{has_zone, read_zone_cars, write_zone_cars},
+};
+
+const GEOHASH_ALPHABET: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+const MAX_GEOHASH_LEN: u32 = 12;
+
+pub fn validate_zone(name: &String, geohash_prefix: &String) -> Result<(), Error> {
+    let len = geohash_prefix.len();
+
+    if name.is_empty() || len == 0 || len > MAX_GEOHASH_LEN {
+        return Err(Error::InvalidZone);
+    }
+
+    let mut buffer = [0u8; MAX_GEOHASH_LEN as usize];
+    geohash_prefix.copy_into_slice(&mut buffer[..len as usize]);
+
+    if buffer[..len as usize].iter().any(|c| !GEOHASH_ALPHABET.contains(c)) {
+        return Err(Error::InvalidZone);
+    }
+
+    Ok(())
+}
+
+// la zona tiene que existir y el punto de recogida no puede quedar vacio
+pub fn validate_car_location(env: &Env, zone_id: u32, pickup_point: &String) -> Result<(), Error> {
+    if !has_zone(env, zone_id) {
+        return Err(Error::ZoneNotFound);
+    }
+
+    if pickup_point.is_empty() {
+        return Err(Error::InvalidZone);
+    }
+
+    Ok(())
+}
+
+pub fn add_to_zone_index(env: &Env, zone_id: u32, owner: &Address) {
+    let mut cars = read_zone_cars(env, zone_id);
+
+    if add_to_index(&mut cars, owner) {
+        write_zone_cars(env, zone_id, &cars);
+    }
+}
+
+pub fn remove_from_zone_index(env: &Env, zone_id: u32, owner: &Address) {
+    let mut cars = read_zone_cars(env, zone_id);
+
+    if remove_from_index(&mut cars, owner) {
+        write_zone_cars(env, zone_id, &cars);
+    }
+}
@@ -1,6 +1,6 @@
 use soroban_sdk::{Address, Env};
 
-use super::types::storage::DataKey;
+use super::types::{errors::Error, storage::DataKey};
 
 pub(crate) fn has_admin(env: &Env) -> bool {
     let key = DataKey::Admin;
@@ -8,10 +8,10 @@ pub(crate) fn has_admin(env: &Env) -> bool {
     env.storage().instance().has(&key)
 }
 
-pub(crate) fn read_admin(env: &Env) -> Address {
+pub(crate) fn read_admin(env: &Env) -> Result<Address, Error> {
     let key = DataKey::Admin;
 
-    env.storage().instance().get(&key).unwrap()
+    env.storage().instance().get(&key).ok_or(Error::ContractNotInitialized)
 }
 
 
@@ -21,6 +21,23 @@ pub(crate) fn write_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&key, admin);
 }
 
+pub(crate) fn read_treasury(env: &Env) -> Result<Address, Error> {
+    let key = DataKey::Treasury;
+
+    env.storage().instance().get(&key).ok_or(Error::TreasuryNotSet)
+}
+
+pub(crate) fn write_treasury(env: &Env, treasury: &Address) {
+    let key = DataKey::Treasury;
+
+    env.storage().instance().set(&key, treasury);
+}
+
+// si hay una treasury configurada las comisiones se envian ahi, si no al que las retira
+pub(crate) fn read_commission_recipient(env: &Env, caller: &Address) -> Address {
+    read_treasury(env).unwrap_or_else(|_| caller.clone())
+}
+
 pub(crate) fn write_commission(env: &Env, commission: i128) {
     let key = DataKey::AdminCommission;
 
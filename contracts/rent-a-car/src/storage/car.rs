@@ -1,13 +1,16 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, Map, Vec};
 
-use crate::storage::{structs::car::Car, types::storage::DataKey};
+use crate::storage::{structs::car::Car, types::{errors::Error, storage::DataKey}};
 
 pub(crate) fn has_car(env: &Env, owner: &Address) -> bool {
     env.storage().instance().has(&DataKey::Car(owner.clone()))
 }
 
-pub(crate) fn read_car(env: &Env, owner: &Address) -> Car {
-    env.storage().instance().get(&DataKey::Car(owner.clone())).unwrap()
+pub(crate) fn read_car(env: &Env, owner: &Address) -> Result<Car, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Car(owner.clone()))
+        .ok_or(Error::CarNotFound)
 }
 
 pub(crate) fn write_car(env: &Env, owner: &Address, car: &Car) {
@@ -16,4 +19,59 @@ pub(crate) fn write_car(env: &Env, owner: &Address, car: &Car) {
 
 pub(crate) fn remove_car(env: &Env, owner: &Address) {
     env.storage().instance().remove(&DataKey::Car(owner.clone()));
-}
\ No newline at end of file
+}
+
+// devuelve el owner con el que se registro el carro del NFT
+pub(crate) fn read_car_token(env: &Env, token_id: u32) -> Result<Address, Error> {
+    env.storage().instance().get(&DataKey::CarToken(token_id)).ok_or(Error::CarNotFound)
+}
+
+pub(crate) fn write_car_token(env: &Env, token_id: u32, owner: &Address) {
+    env.storage().instance().set(&DataKey::CarToken(token_id), owner);
+}
+
+pub(crate) fn remove_car_token(env: &Env, token_id: u32) {
+    env.storage().instance().remove(&DataKey::CarToken(token_id));
+}
+
+// ingresos que un holder acumulo antes de transferir el NFT del carro
+pub(crate) fn read_holder_credit(env: &Env, holder: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HolderCredit(holder.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_holder_credit(env: &Env, holder: &Address, credit: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::HolderCredit(holder.clone()), &credit);
+}
+
+// credito de holders anteriores retenido hasta que termine la renta en curso del carro
+pub(crate) fn read_held_holder_credit(env: &Env, owner: &Address) -> Map<Address, i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HeldHolderCredit(owner.clone()))
+        .unwrap_or(Map::new(env))
+}
+
+pub(crate) fn write_held_holder_credit(env: &Env, owner: &Address, held: &Map<Address, i128>) {
+    env.storage().persistent().set(&DataKey::HeldHolderCredit(owner.clone()), held);
+}
+
+pub(crate) fn remove_held_holder_credit(env: &Env, owner: &Address) {
+    env.storage().persistent().remove(&DataKey::HeldHolderCredit(owner.clone()));
+}
+
+// owners de los carros cuyo NFT tiene el holder, en orden de llegada
+pub(crate) fn read_holder_cars(env: &Env, holder: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HolderCars(holder.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_holder_cars(env: &Env, holder: &Address, cars: &Vec<Address>) {
+    env.storage().persistent().set(&DataKey::HolderCars(holder.clone()), cars);
+}
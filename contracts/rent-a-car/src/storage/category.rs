@@ -0,0 +1,39 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{
+    structs::category::CategoryRules,
+    types::{car_category::CarCategory, errors::Error, storage::DataKey},
+};
+
+pub(crate) fn read_category_rules(env: &Env, category: &CarCategory) -> Result<CategoryRules, Error> {
+    env.storage().instance().get(&DataKey::CategoryRules(category.clone())).ok_or(Error::CategoryRulesNotSet)
+}
+
+pub(crate) fn write_category_rules(env: &Env, category: &CarCategory, rules: &CategoryRules) {
+    env.storage().instance().set(&DataKey::CategoryRules(category.clone()), rules);
+}
+
+// owners de los carros de la categoria, en orden de alta
+pub(crate) fn read_category_cars(env: &Env, category: &CarCategory) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CategoryCars(category.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_category_cars(env: &Env, category: &CarCategory, cars: &Vec<Address>) {
+    env.storage().persistent().set(&DataKey::CategoryCars(category.clone()), cars);
+}
+
+pub(crate) fn read_renter_verification(env: &Env, renter: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RenterVerification(renter.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_renter_verification(env: &Env, renter: &Address, level: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RenterVerification(renter.clone()), &level);
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{structs::co_owner::CoOwner, types::storage::DataKey};
+
+pub(crate) fn has_co_owners(env: &Env, owner: &Address) -> bool {
+    env.storage().instance().has(&DataKey::CoOwners(owner.clone()))
+}
+
+pub(crate) fn read_co_owners(env: &Env, owner: &Address) -> Vec<CoOwner> {
+    env.storage()
+        .instance()
+        .get(&DataKey::CoOwners(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_co_owners(env: &Env, owner: &Address, co_owners: &Vec<CoOwner>) {
+    env.storage().instance().set(&DataKey::CoOwners(owner.clone()), co_owners);
+}
+
+pub(crate) fn remove_co_owners(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::CoOwners(owner.clone()));
+}
+
+// lo que cada co-owner puede retirar de un carro
+pub(crate) fn read_co_owner_balance(env: &Env, owner: &Address, co_owner: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CoOwnerBalance(owner.clone(), co_owner.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_co_owner_balance(env: &Env, owner: &Address, co_owner: &Address, balance: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CoOwnerBalance(owner.clone(), co_owner.clone()), &balance);
+}
@@ -0,0 +1,26 @@
+use soroban_sdk::Env;
+
+use crate::storage::{
+    structs::condition::ConditionReport,
+    types::{errors::Error, reading_kind::ReadingKind, storage::DataKey},
+};
+
+// los reportes quedan despues de completar la renta para disputas y reclamos
+pub(crate) fn has_condition_report(env: &Env, rental_id: u32, kind: &ReadingKind) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::ConditionReport(rental_id, kind.clone()))
+}
+
+pub(crate) fn read_condition_report(env: &Env, rental_id: u32, kind: &ReadingKind) -> Result<ConditionReport, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ConditionReport(rental_id, kind.clone()))
+        .ok_or(Error::ConditionReportNotFound)
+}
+
+pub(crate) fn write_condition_report(env: &Env, rental_id: u32, kind: &ReadingKind, report: &ConditionReport) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ConditionReport(rental_id, kind.clone()), report);
+}
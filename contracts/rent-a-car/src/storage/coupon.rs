@@ -0,0 +1,28 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::storage::{structs::coupon::Coupon, types::{errors::Error, storage::DataKey}};
+
+pub(crate) fn has_coupon(env: &Env, code_hash: &BytesN<32>) -> bool {
+    env.storage().persistent().has(&DataKey::Coupon(code_hash.clone()))
+}
+
+pub(crate) fn read_coupon(env: &Env, code_hash: &BytesN<32>) -> Result<Coupon, Error> {
+    env.storage().persistent().get(&DataKey::Coupon(code_hash.clone())).ok_or(Error::CouponNotFound)
+}
+
+pub(crate) fn write_coupon(env: &Env, code_hash: &BytesN<32>, coupon: &Coupon) {
+    env.storage().persistent().set(&DataKey::Coupon(code_hash.clone()), coupon);
+}
+
+pub(crate) fn read_coupon_usage(env: &Env, code_hash: &BytesN<32>, renter: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CouponUsage(code_hash.clone(), renter.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_coupon_usage(env: &Env, code_hash: &BytesN<32>, renter: &Address, usage: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CouponUsage(code_hash.clone(), renter.clone()), &usage);
+}
@@ -0,0 +1,31 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::storage::types::{errors::Error, storage::DataKey};
+
+pub(crate) fn read_car_device(env: &Env, owner: &Address) -> Result<BytesN<32>, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarDevice(owner.clone()))
+        .ok_or(Error::DeviceNotRegistered)
+}
+
+pub(crate) fn write_car_device(env: &Env, owner: &Address, public_key: &BytesN<32>) {
+    env.storage().persistent().set(&DataKey::CarDevice(owner.clone()), public_key);
+}
+
+pub(crate) fn remove_car_device(env: &Env, owner: &Address) {
+    env.storage().persistent().remove(&DataKey::CarDevice(owner.clone()));
+    env.storage().persistent().remove(&DataKey::DeviceNonce(owner.clone()));
+}
+
+// ultimo nonce aceptado para el dispositivo del carro, 0 si nunca reporto
+pub(crate) fn read_device_nonce(env: &Env, owner: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DeviceNonce(owner.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_device_nonce(env: &Env, owner: &Address, nonce: u64) {
+    env.storage().persistent().set(&DataKey::DeviceNonce(owner.clone()), &nonce);
+}
@@ -0,0 +1,49 @@
+use soroban_sdk::Env;
+
+use crate::storage::{
+    structs::insurance::{InsuranceClaim, InsuranceConfig, InsuredRental},
+    types::{errors::Error, storage::DataKey},
+};
+
+pub(crate) fn read_insurance_config(env: &Env) -> Result<InsuranceConfig, Error> {
+    env.storage().instance().get(&DataKey::InsuranceConfig).ok_or(Error::InsuranceNotConfigured)
+}
+
+pub(crate) fn write_insurance_config(env: &Env, config: &InsuranceConfig) {
+    env.storage().instance().set(&DataKey::InsuranceConfig, config);
+}
+
+// balance del pool, separado de ContractBalance y AdminCommission
+pub(crate) fn read_insurance_pool(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InsurancePool)
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_insurance_pool(env: &Env, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::InsurancePool, &amount);
+}
+
+pub(crate) fn read_insured_rental(env: &Env, rental_id: u32) -> Result<InsuredRental, Error> {
+    env.storage().persistent().get(&DataKey::InsuredRental(rental_id)).ok_or(Error::RentalNotFound)
+}
+
+pub(crate) fn write_insured_rental(env: &Env, rental_id: u32, insured: &InsuredRental) {
+    env.storage().persistent().set(&DataKey::InsuredRental(rental_id), insured);
+}
+
+// cada renta asegurada admite un solo reclamo, guardado con su rental_id
+pub(crate) fn has_claim(env: &Env, rental_id: u32) -> bool {
+    env.storage().persistent().has(&DataKey::InsuranceClaim(rental_id))
+}
+
+pub(crate) fn read_claim(env: &Env, rental_id: u32) -> Result<InsuranceClaim, Error> {
+    env.storage().persistent().get(&DataKey::InsuranceClaim(rental_id)).ok_or(Error::ClaimNotFound)
+}
+
+pub(crate) fn write_claim(env: &Env, rental_id: u32, claim: &InsuranceClaim) {
+    env.storage().persistent().set(&DataKey::InsuranceClaim(rental_id), claim);
+}
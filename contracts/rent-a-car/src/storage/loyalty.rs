@@ -0,0 +1,11 @@
+use soroban_sdk::Env;
+
+use crate::storage::{structs::loyalty::LoyaltyConfig, types::{errors::Error, storage::DataKey}};
+
+pub(crate) fn read_loyalty_config(env: &Env) -> Result<LoyaltyConfig, Error> {
+    env.storage().instance().get(&DataKey::LoyaltyConfig).ok_or(Error::LoyaltyNotConfigured)
+}
+
+pub(crate) fn write_loyalty_config(env: &Env, config: &LoyaltyConfig) {
+    env.storage().instance().set(&DataKey::LoyaltyConfig, config);
+}
@@ -4,4 +4,16 @@ pub mod rental;
 pub mod token;
 pub mod types;
 pub mod structs;
-pub mod contract_balance;
\ No newline at end of file
+pub mod contract_balance;
+pub mod withdrawal;
+pub mod pricing;
+pub mod coupon;
+pub mod referral;
+pub mod loyalty;
+pub mod co_owner;
+pub mod insurance;
+pub mod zone;
+pub mod category;
+pub mod usage;
+pub mod condition;
+pub mod device;
\ No newline at end of file
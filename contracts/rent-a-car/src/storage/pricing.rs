@@ -0,0 +1,34 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{
+    structs::pricing::{DiscountTier, PricingRules},
+    types::storage::DataKey,
+};
+
+// sin reglas el carro se cobra a precio plano
+pub(crate) fn read_pricing_rules(env: &Env, owner: &Address) -> Option<PricingRules> {
+    env.storage().instance().get(&DataKey::PricingRules(owner.clone()))
+}
+
+pub(crate) fn write_pricing_rules(env: &Env, owner: &Address, rules: &PricingRules) {
+    env.storage().instance().set(&DataKey::PricingRules(owner.clone()), rules);
+}
+
+pub(crate) fn remove_pricing_rules(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::PricingRules(owner.clone()));
+}
+
+pub(crate) fn read_discount_tiers(env: &Env, owner: &Address) -> Vec<DiscountTier> {
+    env.storage()
+        .instance()
+        .get(&DataKey::DiscountTiers(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_discount_tiers(env: &Env, owner: &Address, tiers: &Vec<DiscountTier>) {
+    env.storage().instance().set(&DataKey::DiscountTiers(owner.clone()), tiers);
+}
+
+pub(crate) fn remove_discount_tiers(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::DiscountTiers(owner.clone()));
+}
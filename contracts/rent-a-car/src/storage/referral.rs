@@ -0,0 +1,37 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::{errors::Error, storage::DataKey};
+
+pub(crate) fn has_referrer(env: &Env, renter: &Address) -> bool {
+    env.storage().persistent().has(&DataKey::Referrer(renter.clone()))
+}
+
+pub(crate) fn read_referrer(env: &Env, renter: &Address) -> Result<Address, Error> {
+    env.storage().persistent().get(&DataKey::Referrer(renter.clone())).ok_or(Error::ReferrerNotFound)
+}
+
+pub(crate) fn write_referrer(env: &Env, renter: &Address, referrer: &Address) {
+    env.storage().persistent().set(&DataKey::Referrer(renter.clone()), referrer);
+}
+
+// porcentaje de la comision (bps) que se lleva el referidor
+pub(crate) fn read_referral_share(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::ReferralShare).unwrap_or(0)
+}
+
+pub(crate) fn write_referral_share(env: &Env, share_bps: u32) {
+    env.storage().instance().set(&DataKey::ReferralShare, &share_bps);
+}
+
+pub(crate) fn read_referral_balance(env: &Env, referrer: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReferralBalance(referrer.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_referral_balance(env: &Env, referrer: &Address, balance: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReferralBalance(referrer.clone()), &balance);
+}
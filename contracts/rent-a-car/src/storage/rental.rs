@@ -1,6 +1,6 @@
 use soroban_sdk::{Address, Env};
 
-use crate::storage::{structs::rental::Rental, types::storage::DataKey};
+use crate::storage::{structs::rental::Rental, types::{errors::Error, storage::DataKey}};
 
 pub(crate) fn has_rental(env: &Env, renter: &Address, car_owner: &Address) -> bool {
     let key = DataKey::Rental(renter.clone(), car_owner.clone());
@@ -11,10 +11,21 @@ pub(crate) fn write_rental(env: &Env, renter: &Address, car_owner: &Address, ren
     env.storage().instance().set(&DataKey::Rental(renter.clone(), car_owner.clone()), rental);
 }
 
-pub(crate) fn read_rental(env: &Env, renter: &Address, car_owner: &Address) -> Rental {
-    env.storage().instance().get(&DataKey::Rental(renter.clone(), car_owner.clone())).unwrap()
+pub(crate) fn read_rental(env: &Env, renter: &Address, car_owner: &Address) -> Result<Rental, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Rental(renter.clone(), car_owner.clone()))
+        .ok_or(Error::RentalNotFound)
 }
 
 pub(crate) fn remove_rental(env: &Env, renter: &Address, car_owner: &Address) {
     env.storage().instance().remove(&DataKey::Rental(renter.clone(), car_owner.clone()));
+}
+
+// devuelve el siguiente id disponible para una renta
+pub(crate) fn next_rental_id(env: &Env) -> u32 {
+    let id: u32 = env.storage().instance().get(&DataKey::RentalCounter).unwrap_or(0);
+
+    env.storage().instance().set(&DataKey::RentalCounter, &(id + 1));
+    id
 }
\ No newline at end of file
@@ -1,6 +1,9 @@
-use soroban_sdk::{contracttype};
+use soroban_sdk::{contracttype, Address};
 
-use crate::storage::types::car_status::CarStatus;
+use crate::storage::{
+    structs::zone::CarLocation,
+    types::{car_category::CarCategory, car_status::CarStatus},
+};
 
 #[derive(Clone)]
 #[contracttype]
@@ -9,5 +12,15 @@ pub struct Car {
     // cuando es u es unsigned, solo positivos
     // realmente considero que aquí podemos poner u128, porque el precio nunca sera negativo
     pub car_status: CarStatus,
+    pub category: CarCategory,
     pub available_to_withdraw: i128,
+    pub pending_to_withdraw: i128, // credito de rentas que el renter todavia no recogio
+    pub pickup_code_required: bool, // el credito queda pendiente hasta confirm_pickup
+    pub driver_fee_per_day: i128, // cargo por cada conductor adicional, 0 = gratis
+    pub min_days: u32, // 0 = sin minimo
+    pub max_days: u32, // 0 = sin limite
+    pub min_notice_secs: u64, // anticipacion minima entre la reserva y start_at
+    pub payout_address: Address, // a donde se envian los payouts, por defecto el owner
+    pub token_id: u32, // NFT del carro, su holder es quien cobra y administra el carro
+    pub location: Option<CarLocation>, // zona y punto de recogida, None hasta que se asigna
 }
\ No newline at end of file
@@ -0,0 +1,9 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct CategoryRules {
+    pub min_verification_level: u32, // nivel minimo de verificacion del renter, 0 = sin requisito
+    pub max_rental_days: u32, // 0 = sin limite
+    pub deposit_floor: i128, // deposito que se retiene en cada renta y se devuelve al completarla
+}
@@ -0,0 +1,8 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct CoOwner {
+    pub address: Address,
+    pub share_bps: u32, // la suma de todos los co-owners debe ser 10_000
+}
@@ -0,0 +1,10 @@
+use soroban_sdk::{contracttype, Address, BytesN, Vec};
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct ConditionReport {
+    pub renter: Address,
+    pub holder: Address, // holder del NFT que firmo el reporte junto al renter
+    pub evidence: Vec<BytesN<32>>, // hashes del contenido de las fotos en IPFS
+    pub timestamp: u64,
+}
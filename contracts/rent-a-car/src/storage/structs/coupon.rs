@@ -0,0 +1,12 @@
+use soroban_sdk::{contracttype};
+
+use crate::storage::types::coupon_discount::CouponDiscount;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct Coupon {
+    pub discount: CouponDiscount,
+    pub uses_remaining: u32,
+    pub per_address_limit: u32, // cuantas veces lo puede usar cada renter
+    pub expires_at: u64,
+}
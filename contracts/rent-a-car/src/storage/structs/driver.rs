@@ -0,0 +1,9 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct RentalDriver {
+    pub address: Address,
+    pub accepted: bool, // el conductor tiene que aceptar con su propia firma
+    pub fee: i128, // lo paga el renter al invitarlo, se le devuelve si nunca acepta
+}
@@ -0,0 +1,33 @@
+use soroban_sdk::{contracttype, Address};
+
+use crate::storage::types::claim_status::ClaimStatus;
+
+#[derive(Clone)]
+#[contracttype]
+pub struct InsuranceConfig {
+    pub premium_bps: u32, // parte de cada renta que va al pool de seguro
+    pub max_claim_amount: i128, // tope que se puede pagar por reclamo
+    pub claim_window_secs: u64, // tiempo despues de la devolucion para presentar un reclamo
+}
+
+// renta que pago prima, se guarda por rental_id para poder reclamar despues de completarla
+#[derive(Clone)]
+#[contracttype]
+pub struct InsuredRental {
+    pub owner: Address,
+    pub renter: Address,
+    pub holder: Address, // holder del carro al rentar, es quien puede reclamar
+    pub returned_at: Option<u64>, // se llena en complete_rental
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct InsuranceClaim {
+    pub rental_id: u32,
+    pub owner: Address, // carro sobre el que se hizo la renta
+    pub renter: Address,
+    pub claimant: Address, // holder del carro al presentar el reclamo, recibe el pago
+    pub amount: i128, // monto solicitado
+    pub paid: i128, // monto aprobado y pagado desde el pool
+    pub status: ClaimStatus,
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone)]
+#[contracttype]
+pub struct LoyaltyConfig {
+    pub token: Address, // contrato loyalty-token, este contrato tiene que ser su owner para mintear
+    pub earn_rate_bps: u32, // puntos que se mintean por cada unidad pagada, 10_000 = 1:1
+    pub redeem_rate_bps: u32, // descuento por cada punto quemado, 10_000 = 1:1
+}
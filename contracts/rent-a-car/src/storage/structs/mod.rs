@@ -1,2 +1,13 @@
 pub mod car;
-pub mod rental;
\ No newline at end of file
+pub mod rental;
+pub mod withdrawal;
+pub mod pricing;
+pub mod coupon;
+pub mod loyalty;
+pub mod co_owner;
+pub mod insurance;
+pub mod zone;
+pub mod category;
+pub mod usage;
+pub mod condition;
+pub mod driver;
\ No newline at end of file
@@ -0,0 +1,24 @@
+use soroban_sdk::{contracttype, Vec};
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct SeasonalRate {
+    pub start: u64, // timestamp en segundos, inclusivo
+    pub end: u64, // timestamp en segundos, exclusivo
+    pub multiplier_bps: u32, // 10_000 = precio normal
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct PricingRules {
+    pub weekday_multipliers_bps: Vec<u32>, // 7 valores, lunes = 0 ... domingo = 6
+    pub seasonal_rates: Vec<SeasonalRate>, // temporadas y feriados, gana la primera que coincida
+}
+
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct DiscountTier {
+    pub min_days: u32, // se aplica desde este numero de dias
+    pub discount_bps: u32, // 1_000 = -10%
+}
@@ -1,8 +1,24 @@
-use soroban_sdk::{contracttype};
+use soroban_sdk::{contracttype, BytesN, Vec};
+
+use crate::storage::structs::{driver::RentalDriver, usage::UsageReading};
 
 #[derive(Clone)]
 #[contracttype]
 pub struct Rental {
+    pub id: u32,
     pub total_days_to_rent: u32,
+    pub start_at: u64, // inicio pedido por el renter
     pub amount: i128,
+    pub discount: i128, // descuento por renta larga ya restado de amount
+    pub coupon_discount: i128, // descuento del cupon, lo absorbe la comision
+    pub loyalty_discount: i128, // descuento por puntos quemados, tambien lo absorbe la comision
+    pub insurance_premium: i128, // incluido en amount, va al pool de seguro
+    pub deposit: i128, // incluido en amount, se le devuelve al renter al completar la renta
+    pub pickup_reading: Option<UsageReading>,
+    pub return_reading: Option<UsageReading>,
+    pub started_at: Option<u64>, // inicio real: primer unlock del dispositivo o confirm_pickup
+    pub ended_at: Option<u64>, // ultimo lock reportado por el dispositivo
+    pub pickup_code_hash: Option<BytesN<32>>, // sha256 del codigo que el owner le entrega al renter
+    pub pending_credit: i128, // credito del owner que se libera cuando arranca la renta
+    pub drivers: Vec<RentalDriver>, // conductores adicionales invitados por el renter
 }
\ No newline at end of file
@@ -0,0 +1,17 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct UsageFees {
+    pub included_km_per_day: u32, // km incluidos en el precio por cada dia rentado
+    pub price_per_extra_km: i128,
+    pub refuel_fee_per_pct: i128, // por cada punto de combustible/carga que falte al devolver
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct UsageReading {
+    pub odometer_km: u32,
+    pub fuel_level_pct: u32, // 0 - 100, combustible o carga de la bateria
+    pub confirmed: bool, // la reporta el renter y la confirma el owner
+}
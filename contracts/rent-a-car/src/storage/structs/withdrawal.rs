@@ -0,0 +1,30 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+use crate::storage::types::withdrawal_status::WithdrawalStatus;
+
+#[derive(Clone)]
+#[contracttype]
+pub struct WithdrawalPolicy {
+    pub approvers: Vec<Address>,
+    pub required_approvals: u32, // M de N
+    pub amount_threshold: i128, // retiros por encima de este monto necesitan aprobaciones
+    pub proposal_ttl: u64, // segundos que dura una propuesta antes de expirar
+    pub period_secs: u64, // ventana en la que se suman los retiros directos contra amount_threshold
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct WithdrawalWindow {
+    pub started_at: u64,
+    pub withdrawn: i128, // retirado con payout_admin desde started_at, sin contar propuestas aprobadas
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct WithdrawalProposal {
+    pub proposer: Address, // recibe los fondos al ejecutarse si no hay treasury
+    pub amount: i128,
+    pub approvals: Vec<Address>,
+    pub expires_at: u64,
+    pub status: WithdrawalStatus,
+}
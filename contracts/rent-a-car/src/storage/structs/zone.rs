@@ -0,0 +1,15 @@
+use soroban_sdk::{contracttype, String};
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct Zone {
+    pub name: String, // barrio o sector que ve el renter
+    pub geohash_prefix: String, // prefijo geohash que cubre la zona
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct CarLocation {
+    pub zone_id: u32,
+    pub pickup_point: String, // direccion o referencia donde se recoge el carro
+}
@@ -1,11 +1,12 @@
 use soroban_sdk::{Address, Env};
 
-use crate::storage::types::storage::DataKey;
+use crate::storage::types::{errors::Error, storage::DataKey};
 
-pub(crate) fn read_token(env: &Env) -> Address {
+pub(crate) fn read_token(env: &Env) -> Result<Address, Error> {
     env.storage()
         .instance()
-        .get(&DataKey::Token).unwrap()
+        .get(&DataKey::Token)
+        .ok_or(Error::ContractNotInitialized)
 }
 
 pub(crate) fn write_token(env: &Env, token: &Address) {
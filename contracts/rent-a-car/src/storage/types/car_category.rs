@@ -0,0 +1,11 @@
+use soroban_sdk::{contracttype};
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum CarCategory {
+    Economy, //0
+    Suv, //1
+    Luxury, //2
+    Van, //3
+}
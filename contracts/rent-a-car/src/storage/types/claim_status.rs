@@ -0,0 +1,10 @@
+use soroban_sdk::{contracttype};
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum ClaimStatus {
+    Pending, //0
+    Approved, //1
+    Rejected, //2
+}
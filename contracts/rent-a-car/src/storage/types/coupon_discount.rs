@@ -0,0 +1,8 @@
+use soroban_sdk::{contracttype};
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub enum CouponDiscount {
+    Fixed(i128), // monto fijo en stroops
+    Bps(u32), // porcentaje en basis points
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::{contracttype};
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum DeviceAction {
+    Unlock, //0
+    Lock, //1
+}
\ No newline at end of file
@@ -17,5 +17,77 @@ pub enum Error {
     RentalDurationCannotBeZero = 10,
     SelfRentalNotAllowed = 11,
     CarAlreadyRented = 12,
-    CannotPayoutWhileCarIsRented = 13 // nuevo error, para validacion en payout_owner
+    CannotPayoutWhileCarIsRented = 13, // nuevo error, para validacion en payout_owner
+    Unauthorized = 14,
+    RoleNotGranted = 15,
+    InvalidWithdrawalPolicy = 16,
+    WithdrawalRequiresApproval = 17,
+    WithdrawalNotFound = 18,
+    WithdrawalNotPending = 19,
+    WithdrawalExpired = 20,
+    NotAnApprover = 21,
+    WithdrawalAlreadyApproved = 22,
+    TreasuryNotSet = 23,
+    InvalidPricingRules = 24,
+    AmountBelowRentalPrice = 25,
+    InvalidDiscountTiers = 26,
+    InvalidCoupon = 27,
+    CouponAlreadyExists = 28,
+    CouponNotFound = 29,
+    CouponExpired = 30,
+    CouponExhausted = 31,
+    CouponLimitReached = 32,
+    CouponSubsidyExceedsCommission = 33,
+    ReferrerAlreadySet = 34,
+    SelfReferralNotAllowed = 35,
+    ReferrerNotFound = 36,
+    InvalidReferralShare = 37,
+    NothingToClaim = 38,
+    LoyaltyNotConfigured = 39,
+    InvalidLoyaltyConfig = 40,
+    LoyaltyDiscountExceedsPrice = 41,
+    CarNotRented = 42,
+    InvalidCoOwnerShares = 43,
+    InsuranceNotConfigured = 44,
+    InvalidInsuranceConfig = 45,
+    ClaimNotFound = 46,
+    ClaimNotPending = 47,
+    ClaimExceedsCap = 48,
+    InsufficientInsurancePool = 49,
+    ZoneNotFound = 50,
+    InvalidZone = 51,
+    ZoneNotEmpty = 52,
+    CarLocationNotSet = 53,
+    InvalidCategoryRules = 54,
+    RenterVerificationTooLow = 55,
+    RentalTooLong = 56,
+    CategoryRulesNotSet = 57,
+    InvalidReading = 58,
+    ReadingNotSubmitted = 59,
+    ReadingAlreadyConfirmed = 60,
+    InvalidUsageFees = 61,
+    UsageFeesNotSet = 62,
+    InvalidConditionReport = 63,
+    ConditionReportExists = 64,
+    ConditionReportNotFound = 65,
+    DeviceNotRegistered = 66,
+    StaleDeviceReport = 67,
+    RentalNotStarted = 68,
+    PickupCodeNotCommitted = 69,
+    InvalidPickupCode = 70,
+    PickupAlreadyConfirmed = 71,
+    TooManyDrivers = 72,
+    DriverAlreadyAdded = 73,
+    DriverNotInvited = 74,
+    InvalidDriverFee = 75,
+    RentalBelowMinDays = 76,
+    RentalAboveMaxDays = 77,
+    InsufficientNotice = 78,
+    InvalidRentalLimits = 79,
+    ClaimWindowClosed = 80,
+    ClaimAlreadyFiled = 81,
+    NoUsageDebt = 82,
+    OutstandingUsageDebt = 83,
+    ReturnReadingNotConfirmed = 84,
+    AdminRoleNotGrantable = 85,
 }
\ No newline at end of file
@@ -1,3 +1,11 @@
 pub mod storage;
 pub mod car_status;
-pub mod errors;
\ No newline at end of file
+pub mod errors;
+pub mod role;
+pub mod withdrawal_status;
+pub mod coupon_discount;
+pub mod claim_status;
+pub mod car_category;
+
+pub mod reading_kind;
+pub mod device_action;
\ No newline at end of file
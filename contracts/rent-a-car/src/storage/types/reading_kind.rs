@@ -0,0 +1,9 @@
+use soroban_sdk::{contracttype};
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum ReadingKind {
+    Pickup, //0
+    Return, //1
+}
\ No newline at end of file
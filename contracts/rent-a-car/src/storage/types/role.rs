@@ -0,0 +1,23 @@
+use soroban_sdk::{contracttype, Env, Symbol};
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum Role {
+    Admin, //0
+    FleetManager, //1 -> puede agregar y remover carros
+    Treasurer, //2 -> puede retirar las comisiones
+    Arbiter, //3
+}
+
+impl Role {
+    // los roles se guardan como Symbol en el modulo de access control de OpenZeppelin
+    pub fn to_symbol(&self, env: &Env) -> Symbol {
+        match self {
+            Role::Admin => Symbol::new(env, "admin"),
+            Role::FleetManager => Symbol::new(env, "fleet_manager"),
+            Role::Treasurer => Symbol::new(env, "treasurer"),
+            Role::Arbiter => Symbol::new(env, "arbiter"),
+        }
+    }
+}
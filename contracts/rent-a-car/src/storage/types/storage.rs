@@ -1,12 +1,49 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN};
+
+use crate::storage::types::{car_category::CarCategory, reading_kind::ReadingKind};
 
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
     Admin,
     AdminCommission,
+    Treasury,
     Token,
     ContractBalance,
     Car(Address),
+    CarToken(u32),
+    HolderCredit(Address),
+    HeldHolderCredit(Address),
+    HolderCars(Address),
+    CategoryCars(CarCategory),
+    CategoryRules(CarCategory),
+    RenterVerification(Address),
+    Zone(u32),
+    ZoneCars(u32),
+    PricingRules(Address),
+    DiscountTiers(Address),
+    UsageFees(Address),
+    UsageDebt(Address, Address),
+    RenterUsageDebt(Address),
+    CarDevice(Address),
+    DeviceNonce(Address),
+    CoOwners(Address),
+    CoOwnerBalance(Address, Address),
     Rental(Address, Address),
+    RentalCounter,
+    Coupon(BytesN<32>),
+    CouponUsage(BytesN<32>, Address),
+    ReferralShare,
+    LoyaltyConfig,
+    Referrer(Address),
+    ReferralBalance(Address),
+    InsuranceConfig,
+    InsurancePool,
+    InsuredRental(u32),
+    InsuranceClaim(u32),
+    ConditionReport(u32, ReadingKind),
+    WithdrawalPolicy,
+    WithdrawalCounter,
+    Withdrawal(u32),
+    WithdrawalWindow,
 }
\ No newline at end of file
@@ -0,0 +1,44 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{structs::usage::UsageFees, types::{errors::Error, storage::DataKey}};
+
+pub(crate) fn read_usage_fees(env: &Env, owner: &Address) -> Result<UsageFees, Error> {
+    env.storage().instance().get(&DataKey::UsageFees(owner.clone())).ok_or(Error::UsageFeesNotSet)
+}
+
+pub(crate) fn write_usage_fees(env: &Env, owner: &Address, fees: &UsageFees) {
+    env.storage().instance().set(&DataKey::UsageFees(owner.clone()), fees);
+}
+
+pub(crate) fn remove_usage_fees(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::UsageFees(owner.clone()));
+}
+
+
+// cargos por uso que el deposito no alcanzo a cubrir, los paga el renter con settle_usage_debt
+pub(crate) fn read_usage_debt(env: &Env, renter: &Address, owner: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UsageDebt(renter.clone(), owner.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_usage_debt(env: &Env, renter: &Address, owner: &Address, debt: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::UsageDebt(renter.clone(), owner.clone()), &debt);
+}
+
+// deuda total del renter sumando todos los carros, bloquea cualquier renta nueva
+pub(crate) fn read_renter_usage_debt(env: &Env, renter: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RenterUsageDebt(renter.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_renter_usage_debt(env: &Env, renter: &Address, debt: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RenterUsageDebt(renter.clone()), &debt);
+}
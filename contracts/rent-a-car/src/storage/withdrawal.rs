@@ -0,0 +1,45 @@
+use soroban_sdk::Env;
+
+use crate::storage::{
+    structs::withdrawal::{WithdrawalPolicy, WithdrawalProposal, WithdrawalWindow},
+    types::{errors::Error, storage::DataKey},
+};
+
+pub(crate) fn read_withdrawal_policy(env: &Env) -> Result<WithdrawalPolicy, Error> {
+    env.storage().instance().get(&DataKey::WithdrawalPolicy).ok_or(Error::InvalidWithdrawalPolicy)
+}
+
+pub(crate) fn write_withdrawal_policy(env: &Env, policy: &WithdrawalPolicy) {
+    env.storage().instance().set(&DataKey::WithdrawalPolicy, policy);
+}
+
+// devuelve el siguiente id disponible para una propuesta
+pub(crate) fn next_withdrawal_id(env: &Env) -> u32 {
+    let id: u32 = env.storage().instance().get(&DataKey::WithdrawalCounter).unwrap_or(0);
+
+    env.storage().instance().set(&DataKey::WithdrawalCounter, &(id + 1));
+    id
+}
+
+pub(crate) fn read_withdrawal(env: &Env, id: u32) -> Result<WithdrawalProposal, Error> {
+    env.storage().persistent().get(&DataKey::Withdrawal(id)).ok_or(Error::WithdrawalNotFound)
+}
+
+pub(crate) fn write_withdrawal(env: &Env, id: u32, proposal: &WithdrawalProposal) {
+    env.storage().persistent().set(&DataKey::Withdrawal(id), proposal);
+}
+
+// ventana vigente de retiros directos, se reinicia cuando pasa period_secs
+pub(crate) fn read_withdrawal_window(env: &Env, period_secs: u64) -> WithdrawalWindow {
+    let now = env.ledger().timestamp();
+    let window: Option<WithdrawalWindow> = env.storage().instance().get(&DataKey::WithdrawalWindow);
+
+    match window {
+        Some(window) if now < window.started_at.saturating_add(period_secs) => window,
+        _ => WithdrawalWindow { started_at: now, withdrawn: 0 },
+    }
+}
+
+pub(crate) fn write_withdrawal_window(env: &Env, window: &WithdrawalWindow) {
+    env.storage().instance().set(&DataKey::WithdrawalWindow, window);
+}
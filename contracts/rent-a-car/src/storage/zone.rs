@@ -0,0 +1,32 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{structs::zone::Zone, types::{errors::Error, storage::DataKey}};
+
+pub(crate) fn has_zone(env: &Env, zone_id: u32) -> bool {
+    env.storage().persistent().has(&DataKey::Zone(zone_id))
+}
+
+pub(crate) fn read_zone(env: &Env, zone_id: u32) -> Result<Zone, Error> {
+    env.storage().persistent().get(&DataKey::Zone(zone_id)).ok_or(Error::ZoneNotFound)
+}
+
+pub(crate) fn write_zone(env: &Env, zone_id: u32, zone: &Zone) {
+    env.storage().persistent().set(&DataKey::Zone(zone_id), zone);
+}
+
+pub(crate) fn remove_zone(env: &Env, zone_id: u32) {
+    env.storage().persistent().remove(&DataKey::Zone(zone_id));
+    env.storage().persistent().remove(&DataKey::ZoneCars(zone_id));
+}
+
+// owners de los carros asignados a la zona, en orden de asignacion
+pub(crate) fn read_zone_cars(env: &Env, zone_id: u32) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ZoneCars(zone_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_zone_cars(env: &Env, zone_id: u32, cars: &Vec<Address>) {
+    env.storage().persistent().set(&DataKey::ZoneCars(zone_id), cars);
+}
@@ -1,21 +1,23 @@
-use soroban_sdk::{testutils::Address as _, Address, vec, Symbol, IntoVal};
+use soroban_sdk::{testutils::Address as _, Address, vec, symbol_short, Symbol, IntoVal};
+use crate::storage::types::car_category::CarCategory;
 use crate::{storage::{car::read_car, types::car_status::CarStatus}, tests::config::contract::ContractTest};
+use crate::events::{add_car::CarAddedEvent, EVENT_VERSION};
 use crate::tests::config::utils::get_contract_events;
 
 #[test]
 pub fn test_add_car_successfully() {
-    let ContractTest { env,  contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
 
     env.mock_all_auths();
 
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
 
-    contract.add_car( &owner, &price_per_day);
+    contract.add_car(&admin, &owner, &price_per_day, &CarCategory::Economy, &None);
     let contract_events = get_contract_events(&env, &contract.address);
 
     let stored_car = env.as_contract(&contract.address, || {
-        read_car(&env, &owner)
+        read_car(&env, &owner).unwrap()
     });
 
     assert_eq!(stored_car.price_per_day, price_per_day);
@@ -25,14 +27,24 @@ pub fn test_add_car_successfully() {
         contract_events,
         vec![
             &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *symbol_short!("mint").as_val(),
+                    owner.clone().into_val(&env),
+                ],
+                stored_car.token_id.into_val(&env)
+            ),
             (
                 contract.address.clone(),
                 vec![
                     &env,
                     *Symbol::new(&env, "car_added").as_val(),
+                    EVENT_VERSION.into_val(&env),
                     owner.clone().into_val(&env),
                 ],
-                price_per_day.into_val(&env)
+                CarAddedEvent { owner: owner.clone(), car_id: stored_car.token_id, price_per_day }.into_val(&env)
             )
         ]
     );
@@ -41,39 +53,39 @@ pub fn test_add_car_successfully() {
 #[test]
 #[should_panic(expected = "Error(Contract, #6)")]
 pub fn test_add_car_with_zero_price_fails() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, admin, .. } = ContractTest::setup();
 
     env.mock_all_auths();
 
     let owner = Address::generate(&env);
     let price_per_day = 0_i128;
     
-    contract.add_car(&owner, &price_per_day);
+    contract.add_car(&admin, &owner, &price_per_day, &CarCategory::Economy, &None);
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #6)")]
 pub fn test_add_car_with_negative_price_fails() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, admin, .. } = ContractTest::setup();
     
     env.mock_all_auths();
 
     let owner = Address::generate(&env);
     let price_per_day = -100_i128;
     
-    contract.add_car(&owner, &price_per_day);
+    contract.add_car(&admin, &owner, &price_per_day, &CarCategory::Economy, &None);
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #4)")]
 pub fn test_add_car_already_exists_fails() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, admin, .. } = ContractTest::setup();
     
     env.mock_all_auths();
     
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
     
-    contract.add_car(&owner, &price_per_day);
-    contract.add_car(&owner, &price_per_day);
+    contract.add_car(&admin, &owner, &price_per_day, &CarCategory::Economy, &None);
+    contract.add_car(&admin, &owner, &price_per_day, &CarCategory::Economy, &None);
 }
\ No newline at end of file
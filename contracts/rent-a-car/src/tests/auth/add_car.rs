@@ -1,11 +1,12 @@
 use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
 
+use crate::storage::{structs::zone::CarLocation, types::car_category::CarCategory};
 use crate::tests::config::contract::ContractTest;
 
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
 pub fn test_unauthorized_user_cannot_add_car() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
 
 		let fake_admin = Address::generate(&env);
     let owner = Address::generate(&env);
@@ -17,8 +18,8 @@ pub fn test_unauthorized_user_cannot_add_car() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "add_car",
-                args: (owner.clone(), price_per_day).into_val(&env),
+                args: (admin.clone(), owner.clone(), price_per_day, CarCategory::Economy, None::<CarLocation>).into_val(&env),
                 sub_invokes: &[],
             },
-        }]).add_car(&owner, &price_per_day);
+        }]).add_car(&admin, &owner, &price_per_day, &CarCategory::Economy, &None);
 }
\ No newline at end of file
@@ -0,0 +1,44 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+
+use crate::storage::types::car_category::CarCategory;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_user_without_role_cannot_set_category_rules() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let category = CarCategory::Luxury;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_category_rules",
+                args: (fake_admin.clone(), category.clone(), 0_u32, 0_u32, 0_i128).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_category_rules(&fake_admin, &category, &0_u32, &0_u32, &0_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_renter_cannot_verify_themselves() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let level = 3_u32;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_renter_verification",
+                args: (renter.clone(), renter.clone(), level).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_renter_verification(&renter, &renter, &level);
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, vec, IntoVal, Address};
+
+use crate::storage::types::car_category::CarCategory;
+use crate::{storage::structs::co_owner::CoOwner, tests::config::contract::ContractTest};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_single_co_owner_cannot_update_share_table() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let investor_a = Address::generate(&env);
+    let investor_b = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_co_owners(&owner, &vec![
+        &env,
+        CoOwner { address: investor_a.clone(), share_bps: 5_000 },
+        CoOwner { address: investor_b.clone(), share_bps: 5_000 },
+    ]);
+
+    // investor_a intenta quedarse con todo sin la firma de investor_b
+    let takeover = vec![&env, CoOwner { address: investor_a.clone(), share_bps: 10_000 }];
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &investor_a,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_co_owners",
+                args: (owner.clone(), takeover.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_co_owners(&owner, &takeover);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_withdraw_co_owner_balance() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let co_owner = Address::generate(&env);
+    let fake_co_owner = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_co_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "withdraw_co_owner_balance",
+                args: (owner.clone(), co_owner.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).withdraw_co_owner_balance(&owner, &co_owner);
+}
@@ -0,0 +1,95 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+
+use crate::storage::types::{car_category::CarCategory, reading_kind::ReadingKind};
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_complete_rental() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "complete_rental",
+                args: (owner.clone(), renter.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).complete_rental(&owner, &renter);
+}
+
+// el owner cierra la renta solo con su firma, aunque el uso supere el deposito
+#[test]
+pub fn test_owner_completes_rental_above_deposit_without_renter_auth() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_usage_fees(&owner, &0_u32, &5_i128, &0_i128);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Pickup, &0_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Pickup);
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Return, &200_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Return);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "complete_rental",
+                args: (owner.clone(), renter.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).complete_rental(&owner, &renter);
+
+    assert_eq!(token.0.balance(&renter), 10_000 - 1500);
+    assert_eq!(contract.get_usage_debt(&renter, &owner), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_owner_cannot_settle_renter_usage_debt() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_usage_fees(&owner, &0_u32, &5_i128, &0_i128);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Pickup, &0_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Pickup);
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Return, &200_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Return);
+    contract.complete_rental(&owner, &renter);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "settle_usage_debt",
+                args: (renter.clone(), owner.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).settle_usage_debt(&renter, &owner);
+}
\ No newline at end of file
@@ -0,0 +1,33 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, vec, Address, BytesN, IntoVal};
+
+use crate::storage::types::{car_category::CarCategory, reading_kind::ReadingKind};
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_renter_alone_cannot_sign_condition_report() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let kind = ReadingKind::Pickup;
+    let evidence = vec![&env, BytesN::from_array(&env, &[1; 32])];
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    // falta la firma del owner
+    contract
+        .mock_auths(&[MockAuth {
+            address: &renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "submit_condition_report",
+                args: (renter.clone(), owner.clone(), kind.clone(), evidence.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).submit_condition_report(&renter, &owner, &kind, &evidence);
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Bytes, BytesN, IntoVal, Address};
+
+use crate::{storage::types::coupon_discount::CouponDiscount, tests::config::contract::ContractTest};
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_non_admin_cannot_create_coupon() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let code_hash: BytesN<32> = env.crypto().sha256(&Bytes::from_slice(&env, b"FREE")).into();
+    let discount = CouponDiscount::Bps(10_000);
+    let expires_at = 3600_u64;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "create_coupon",
+                args: (fake_admin.clone(), code_hash.clone(), discount.clone(), 10_u32, 1_u32, expires_at).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).create_coupon(&fake_admin, &code_hash, &discount, &10_u32, &1_u32, &expires_at);
+}
@@ -0,0 +1,28 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address, BytesN, IntoVal};
+
+use crate::storage::types::car_category::CarCategory;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_stranger_cannot_register_car_device() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let public_key = BytesN::from_array(&env, &[1; 32]);
+
+    env.mock_all_auths();
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &stranger,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_car_device",
+                args: (owner.clone(), public_key.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_car_device(&owner, &public_key);
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address, IntoVal};
+
+use crate::storage::types::car_category::CarCategory;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_renter_cannot_accept_on_behalf_of_driver() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let driver = Address::generate(&env);
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+    contract.add_rental_driver(&renter, &owner, &driver);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "accept_rental_driver",
+                args: (driver.clone(), renter.clone(), owner.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).accept_rental_driver(&driver, &renter, &owner);
+}
\ No newline at end of file
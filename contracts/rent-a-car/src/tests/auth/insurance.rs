@@ -0,0 +1,54 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+
+use crate::storage::types::car_category::CarCategory;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_user_without_role_cannot_approve_claim() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_arbiter = Address::generate(&env);
+    let rental_id = 0_u32;
+    let amount = 100_i128;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_arbiter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "approve_insurance_claim",
+                args: (fake_arbiter.clone(), rental_id, amount).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).approve_insurance_claim(&fake_arbiter, &rental_id, &amount);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_file_claim() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let rental_id = 0_u32;
+    let fake_owner = Address::generate(&env);
+    let amount = 100_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &1575_i128);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_insurance_config(&admin, &500_u32, &1000_i128, &86_400_u64);
+    contract.rental(&renter, &owner, &1_u32, &1575_i128, &None, &0, &0);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "file_insurance_claim",
+                args: (owner.clone(), rental_id, amount).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).file_insurance_claim(&owner, &rental_id, &amount);
+}
@@ -1,4 +1,24 @@
 pub mod add_car;
 pub mod payout_owner;
 pub mod rental;
-pub mod remove_car;
\ No newline at end of file
+pub mod remove_car;
+pub mod roles;
+pub mod withdrawal;
+pub mod payout_admin;
+pub mod payout_address;
+pub mod withdraw_all;
+pub mod pricing;
+pub mod coupon;
+pub mod referral;
+pub mod complete_rental;
+pub mod nft;
+pub mod co_owner;
+pub mod insurance;
+pub mod zone;
+pub mod category;
+pub mod usage;
+pub mod condition;
+pub mod device;
+pub mod pickup;
+pub mod driver;
+pub mod rental_limits;
\ No newline at end of file
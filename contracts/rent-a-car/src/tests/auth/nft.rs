@@ -0,0 +1,83 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+
+use crate::storage::types::car_category::CarCategory;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_previous_owner_cannot_payout_after_transfer() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let amount = 100_i128;
+
+    env.mock_all_auths();
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.transfer(&owner, &buyer, &contract.get_car_token_id(&owner));
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "payout_owner",
+                args: (owner.clone(), amount).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).payout_owner(&owner, &amount);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_update_price() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+    let price_per_day = 2000_i128;
+
+    env.mock_all_auths();
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "update_price",
+                args: (owner.clone(), price_per_day).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).update_price(&owner, &price_per_day);
+}
+
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_buyer_cannot_withdraw_seller_holder_credit() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 100_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &4500_i128);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &3_u32, &4500_i128, &None, &0, &0);
+    contract.complete_rental(&owner, &renter);
+    contract.transfer(&owner, &buyer, &contract.get_car_token_id(&owner));
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &buyer,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "withdraw_holder_credit",
+                args: (owner.clone(), amount).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).withdraw_holder_credit(&owner, &amount);
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+
+use crate::storage::types::car_category::CarCategory;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_payout_address() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_payout_address",
+                args: (owner.clone(), fake_owner.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_payout_address(&owner, &fake_owner);
+}
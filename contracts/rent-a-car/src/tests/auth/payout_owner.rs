@@ -1,16 +1,20 @@
 use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
 
+use crate::storage::types::car_category::CarCategory;
 use crate::tests::config::contract::ContractTest;
 
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
 pub fn test_unauthorized_user_cannot_payout_owner() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let fake_owner = Address::generate(&env);
     let amount = 4500_i128;
 
+    env.mock_all_auths();
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+
     contract
         .mock_auths(&[MockAuth {
             address: &fake_owner,
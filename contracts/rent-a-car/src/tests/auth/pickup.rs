@@ -0,0 +1,31 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address, BytesN, IntoVal};
+
+use crate::storage::types::car_category::CarCategory;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_renter_cannot_commit_pickup_code() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let code_hash = BytesN::from_array(&env, &[9; 32]);
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "commit_pickup_code",
+                args: (owner.clone(), renter.clone(), code_hash.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).commit_pickup_code(&owner, &renter, &code_hash);
+}
\ No newline at end of file
@@ -0,0 +1,29 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address, Vec};
+
+use crate::storage::types::car_category::CarCategory;
+use crate::{storage::structs::pricing::SeasonalRate, tests::config::contract::ContractTest};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_pricing_rules() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+    let weekday_multipliers_bps: Vec<u32> = Vec::new(&env);
+    let seasonal_rates: Vec<SeasonalRate> = Vec::new(&env);
+
+    env.mock_all_auths();
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_pricing_rules",
+                args: (owner.clone(), weekday_multipliers_bps.clone(), seasonal_rates.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_pricing_rules(&owner, &weekday_multipliers_bps, &seasonal_rates);
+}
@@ -0,0 +1,43 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_register_referrer() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let referrer = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &referrer,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "register_referrer",
+                args: (renter.clone(), referrer.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).register_referrer(&renter, &referrer);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_claim_referral_rewards() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let referrer = Address::generate(&env);
+    let fake_referrer = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_referrer,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "claim_referral_rewards",
+                args: (referrer.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).claim_referral_rewards(&referrer);
+}
@@ -5,7 +5,7 @@ use crate::tests::config::contract::ContractTest;
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
 pub fn test_unauthorized_user_cannot_remove_car() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
 
 		let fake_admin = Address::generate(&env);
     let owner = Address::generate(&env);
@@ -16,8 +16,8 @@ pub fn test_unauthorized_user_cannot_remove_car() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "remove_car",
-                args: (owner.clone(),).into_val(&env),
+                args: (admin.clone(), owner.clone()).into_val(&env),
                 sub_invokes: &[],
             },
-        }]).remove_car(&owner);
+        }]).remove_car(&admin, &owner);
 }
\ No newline at end of file
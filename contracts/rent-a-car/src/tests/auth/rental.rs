@@ -1,4 +1,4 @@
-use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address, Bytes};
 
 use crate::tests::config::contract::ContractTest;
 
@@ -19,8 +19,8 @@ pub fn test_unauthorized_user_cannot_rent_car() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "rental",
-                args: (renter.clone(), owner.clone(), total_days, amount).into_val(&env),
+                args: (renter.clone(), owner.clone(), total_days, amount, None::<Bytes>, 0_i128, 0_u64).into_val(&env),
                 sub_invokes: &[],
             },
-        }]).rental(&renter, &owner, &total_days, &amount);
+        }]).rental(&renter, &owner, &total_days, &amount, &None, &0, &0);
 }
\ No newline at end of file
@@ -0,0 +1,27 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address, IntoVal};
+
+use crate::storage::types::car_category::CarCategory;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_stranger_cannot_set_rental_limits() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &stranger,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_rental_limits",
+                args: (owner.clone(), 2_u32, 30_u32, 86_400_u64).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_rental_limits(&owner, &2_u32, &30_u32, &86_400_u64);
+}
\ No newline at end of file
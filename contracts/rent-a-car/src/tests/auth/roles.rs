@@ -0,0 +1,88 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+
+use crate::storage::{structs::zone::CarLocation, types::car_category::CarCategory};
+use crate::{storage::types::role::Role, tests::config::contract::ContractTest};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_grant_role_as_admin() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let account = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "grant_role",
+                args: (admin.clone(), account.clone(), Role::FleetManager).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).grant_role(&admin, &account, &Role::FleetManager);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_non_admin_cannot_grant_role() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let account = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "grant_role",
+                args: (fake_admin.clone(), account.clone(), Role::Treasurer).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).grant_role(&fake_admin, &account, &Role::Treasurer);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_non_admin_cannot_revoke_role() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let treasurer = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.grant_role(&admin, &treasurer, &Role::Treasurer);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "revoke_role",
+                args: (fake_admin.clone(), treasurer.clone(), Role::Treasurer).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).revoke_role(&fake_admin, &treasurer, &Role::Treasurer);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_user_without_role_cannot_add_car() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_manager = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_manager,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "add_car",
+                args: (fake_manager.clone(), owner.clone(), price_per_day, CarCategory::Economy, None::<CarLocation>).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).add_car(&fake_manager, &owner, &price_per_day, &CarCategory::Economy, &None);
+}
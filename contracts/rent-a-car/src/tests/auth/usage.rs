@@ -0,0 +1,83 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+
+use crate::storage::types::{car_category::CarCategory, reading_kind::ReadingKind};
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_renter_cannot_confirm_their_own_reading() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let kind = ReadingKind::Pickup;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+    contract.submit_usage_reading(&renter, &owner, &kind, &500_u32, &100_u32);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "confirm_usage_reading",
+                args: (owner.clone(), renter.clone(), kind.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).confirm_usage_reading(&owner, &renter, &kind);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_renter_cannot_record_return_reading() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Pickup, &500_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Pickup);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "record_return_reading",
+                args: (owner.clone(), renter.clone(), 500_u32, 100_u32).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).record_return_reading(&owner, &renter, &500_u32, &100_u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_stranger_cannot_set_usage_fees() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &stranger,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_usage_fees",
+                args: (owner.clone(), 100_u32, 10_i128, 20_i128).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_usage_fees(&owner, &100_u32, &10_i128, &20_i128);
+}
\ No newline at end of file
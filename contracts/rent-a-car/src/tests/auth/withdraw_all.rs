@@ -0,0 +1,47 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, vec, IntoVal, Address};
+
+use crate::storage::types::car_category::CarCategory;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_withdraw_all() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "withdraw_all",
+                args: (owner.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).withdraw_all(&owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_user_without_role_cannot_batch_payout() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_treasurer = Address::generate(&env);
+    let owners = vec![&env, Address::generate(&env)];
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_treasurer,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "batch_payout",
+                args: (fake_treasurer.clone(), owners.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).batch_payout(&fake_treasurer, &owners);
+}
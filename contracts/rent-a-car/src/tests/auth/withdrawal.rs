@@ -0,0 +1,47 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, vec, IntoVal, Address};
+
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_approve_withdrawal() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let approver = Address::generate(&env);
+    let fake_approver = Address::generate(&env);
+    let id = 0_u32;
+
+    env.mock_all_auths();
+    contract.set_withdrawal_policy(&admin, &vec![&env, approver.clone()], &1_u32, &100_i128, &3600_u64, &86_400_u64);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_approver,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "approve_withdrawal",
+                args: (approver.clone(), id).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).approve_withdrawal(&approver, &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_non_admin_cannot_set_withdrawal_policy() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let approvers = vec![&env, fake_admin.clone()];
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_withdrawal_policy",
+                args: (fake_admin.clone(), approvers.clone(), 1_u32, 100_i128, 3600_u64, 86_400_u64).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_withdrawal_policy(&fake_admin, &approvers, &1_u32, &100_i128, &3600_u64, &86_400_u64);
+}
@@ -0,0 +1,52 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address, String};
+
+use crate::storage::types::car_category::CarCategory;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_user_without_role_cannot_set_zone() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let zone_id = 1_u32;
+    let name = String::from_str(&env, "Escazu");
+    let geohash_prefix = String::from_str(&env, "d1u0");
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_zone",
+                args: (fake_admin.clone(), zone_id, name.clone(), geohash_prefix.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_zone(&fake_admin, &zone_id, &name, &geohash_prefix);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_user_without_role_cannot_move_someone_elses_car() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let zone_id = 1_u32;
+    let pickup_point = String::from_str(&env, "Av. Central 123");
+
+    env.mock_all_auths();
+    contract.set_zone(&admin, &zone_id, &String::from_str(&env, "Escazu"), &String::from_str(&env, "d1u0"));
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &stranger,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_car_location",
+                args: (stranger.clone(), owner.clone(), zone_id, pickup_point.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_car_location(&stranger, &owner, &zone_id, &pickup_point);
+}
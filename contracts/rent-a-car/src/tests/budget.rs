@@ -0,0 +1,553 @@
+extern crate std;
+
+use std::{collections::BTreeMap, format, fs, string::String as StdString, vec::Vec as StdVec};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, Bytes, BytesN, Env, String, Vec};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    methods::device::device::device_report_message,
+    storage::{
+        rental::read_rental,
+        structs::{co_owner::CoOwner, pricing::DiscountTier},
+        types::{coupon_discount::CouponDiscount, device_action::DeviceAction, reading_kind::ReadingKind, role::Role},
+    },
+    tests::config::contract::ContractTest,
+};
+
+// cada entrypoint se compara contra su propio baseline en budget_baselines/<flota>_cars.txt,
+// una linea por entrypoint: `nombre cpu mem read_entries write_entries read_bytes write_bytes`.
+// un entrypoint sin baseline falla, los baselines solo se escriben con BUDGET_BLESS=1.
+// la tolerancia se toma de BUDGET_TOLERANCE_PCT
+const BASELINES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/budget_baselines");
+const DEFAULT_TOLERANCE_PCT: u64 = 10;
+const METRICS: [&str; 6] = ["cpu", "mem", "read_entries", "write_entries", "read_bytes", "write_bytes"];
+
+const START: u64 = 1_704_067_200;
+const PRICE_PER_DAY: i128 = 1_500;
+const DAYS: u32 = 3;
+const DEPOSIT: i128 = 500;
+const RENTER_FUNDS: i128 = 100_000;
+
+struct Budget {
+    path: StdString,
+    fleet: u32,
+    bless: bool,
+    tolerance_pct: u64,
+    baselines: BTreeMap<StdString, [u64; 6]>,
+    measured: BTreeMap<StdString, [u64; 6]>,
+}
+
+impl Budget {
+    fn load(fleet: u32) -> Self {
+        let path = format!("{BASELINES_DIR}/{fleet}_cars.txt");
+        let bless = std::env::var("BUDGET_BLESS").is_ok_and(|value| value == "1");
+        let tolerance_pct = std::env::var("BUDGET_TOLERANCE_PCT")
+            .map(|value| value.parse().expect("invalid BUDGET_TOLERANCE_PCT"))
+            .unwrap_or(DEFAULT_TOLERANCE_PCT);
+        let mut baselines = BTreeMap::new();
+
+        for line in fs::read_to_string(&path).unwrap_or_default().lines() {
+            let mut fields = line.split_whitespace();
+
+            let Some(name) = fields.next() else {
+                continue;
+            };
+
+            let values: StdVec<u64> = fields.map(|value| value.parse().expect("invalid budget baseline")).collect();
+            let values: [u64; 6] = values.try_into().expect("budget baseline needs 6 metrics");
+
+            baselines.insert(StdString::from(name), values);
+        }
+
+        Budget { path, fleet, bless, tolerance_pct, baselines, measured: BTreeMap::new() }
+    }
+
+    // compara los recursos de la ultima llamada con su baseline mas la tolerancia
+    fn check(&mut self, env: &Env, name: &str) {
+        let resources = env.cost_estimate().resources();
+        let measured = [
+            resources.instructions as u64,
+            resources.mem_bytes as u64,
+            resources.read_entries as u64,
+            resources.write_entries as u64,
+            resources.read_bytes as u64,
+            resources.write_bytes as u64,
+        ];
+
+        if !self.bless {
+            let baseline = self.baselines.get(name).unwrap_or_else(|| {
+                panic!("{name} @ {} cars: no budget baseline, run with BUDGET_BLESS=1", self.fleet)
+            });
+
+            for ((metric, value), limit) in METRICS.iter().zip(measured).zip(baseline) {
+                assert!(
+                    value <= limit + limit * self.tolerance_pct / 100,
+                    "{name} @ {} cars: {metric} {value} over baseline {limit}",
+                    self.fleet,
+                );
+            }
+        }
+
+        self.measured.insert(StdString::from(name), measured);
+
+        // cada llamada medida arranca con el presupuesto por defecto
+        env.cost_estimate().budget().reset_default();
+    }
+
+    // con BUDGET_BLESS=1 reescribe los baselines con lo medido, sin el no toca los archivos
+    fn save(self) {
+        let Budget { path, bless, measured, .. } = self;
+
+        if !bless {
+            return;
+        }
+
+        let mut contents = StdString::new();
+
+        for (name, values) in measured.iter() {
+            let values: StdVec<StdString> = values.iter().map(|value| format!("{value}")).collect();
+            contents += &format!("{name} {}\n", values.join(" "));
+        }
+
+        fs::create_dir_all(BASELINES_DIR).expect("cannot create budget baselines dir");
+        fs::write(&path, contents).expect("cannot write budget baselines");
+    }
+}
+
+fn device_key() -> SigningKey {
+    SigningKey::from_bytes(&[7; 32])
+}
+
+fn sign_report(env: &Env, rental_id: u32, action: &DeviceAction, timestamp: u64, nonce: u64) -> BytesN<64> {
+    let mut message = [0u8; 24];
+    device_report_message(env, rental_id, action, timestamp, nonce).copy_into_slice(&mut message);
+
+    BytesN::from_array(env, &device_key().sign(&message).to_bytes())
+}
+
+fn rental_id(test: &ContractTest, renter: &Address, owner: &Address) -> u32 {
+    let ContractTest { env, contract, .. } = test;
+    env.as_contract(&contract.address, || read_rental(env, renter, owner).unwrap().id)
+}
+
+fn setup_fleet(fleet: u32) -> (ContractTest<'static>, Vec<Address>) {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    test.env.ledger().set_timestamp(START);
+    test.env.cost_estimate().budget().reset_unlimited();
+
+    let mut owners = Vec::new(&test.env);
+
+    for _ in 0..fleet {
+        let owner = Address::generate(&test.env);
+        test.contract.add_car(&test.admin, &owner, &PRICE_PER_DAY, &CarCategory::Economy, &None);
+        owners.push_back(owner);
+    }
+
+    test.env.cost_estimate().budget().reset_default();
+    (test, owners)
+}
+
+// configuracion global: roles, treasury, referidos, lealtad, seguro, categorias, zonas y cupones
+fn bench_admin(test: &ContractTest, budget: &mut Budget, renter: &Address, code_hash: &BytesN<32>) {
+    let ContractTest { env, contract, admin, loyalty_token, .. } = test;
+    let treasurer = Address::generate(env);
+
+    contract.get_admin();
+    budget.check(env, "get_admin");
+
+    contract.grant_role(admin, &treasurer, &Role::Treasurer);
+    budget.check(env, "grant_role");
+
+    contract.has_role(&treasurer, &Role::Treasurer);
+    budget.check(env, "has_role");
+
+    contract.revoke_role(admin, &treasurer, &Role::Treasurer);
+    budget.check(env, "revoke_role");
+
+    contract.set_treasury(admin, admin);
+    budget.check(env, "set_treasury");
+
+    contract.get_treasury();
+    budget.check(env, "get_treasury");
+
+    contract.set_referral_share(admin, &1_000_u32);
+    budget.check(env, "set_referral_share");
+
+    contract.get_referral_share();
+    budget.check(env, "get_referral_share");
+
+    contract.set_loyalty_config(admin, &loyalty_token.1, &1_000_u32, &10_000_u32);
+    budget.check(env, "set_loyalty_config");
+
+    contract.get_loyalty_config();
+    budget.check(env, "get_loyalty_config");
+
+    contract.set_insurance_config(admin, &500_u32, &10_000_i128, &604_800_u64);
+    budget.check(env, "set_insurance_config");
+
+    contract.get_insurance_config();
+    budget.check(env, "get_insurance_config");
+
+    contract.set_category_rules(admin, &CarCategory::Economy, &1_u32, &30_u32, &DEPOSIT);
+    budget.check(env, "set_category_rules");
+
+    contract.get_category_rules(&CarCategory::Economy);
+    budget.check(env, "get_category_rules");
+
+    contract.set_renter_verification(admin, renter, &1_u32);
+    budget.check(env, "set_renter_verification");
+
+    contract.get_renter_verification(renter);
+    budget.check(env, "get_renter_verification");
+
+    contract.set_zone(admin, &1_u32, &String::from_str(env, "Escazu"), &String::from_str(env, "d1u0"));
+    budget.check(env, "set_zone");
+
+    contract.get_zone(&1_u32);
+    budget.check(env, "get_zone");
+
+    contract.create_coupon(admin, code_hash, &CouponDiscount::Fixed(50), &10_u32, &1_u32, &(START + 3600));
+    budget.check(env, "create_coupon");
+
+    contract.get_coupon(code_hash);
+    budget.check(env, "get_coupon");
+}
+
+// configuracion del carro que se renta en el resto del benchmark
+fn bench_car(test: &ContractTest, budget: &mut Budget, owner: &Address) {
+    let ContractTest { env, contract, admin, .. } = test;
+
+    contract.add_car(admin, owner, &PRICE_PER_DAY, &CarCategory::Economy, &None);
+    budget.check(env, "add_car");
+
+    contract.get_car_status(owner);
+    budget.check(env, "get_car_status");
+
+    contract.get_car_token_id(owner);
+    budget.check(env, "get_car_token_id");
+
+    contract.get_car_holder(owner);
+    budget.check(env, "get_car_holder");
+
+    contract.update_price(owner, &PRICE_PER_DAY);
+    budget.check(env, "update_price");
+
+    contract.set_payout_address(owner, owner);
+    budget.check(env, "set_payout_address");
+
+    contract.get_payout_address(owner);
+    budget.check(env, "get_payout_address");
+
+    contract.set_rental_limits(owner, &1_u32, &30_u32, &0_u64);
+    budget.check(env, "set_rental_limits");
+
+    contract.set_driver_fee(owner, &100_i128);
+    budget.check(env, "set_driver_fee");
+
+    contract.set_pricing_rules(owner, &vec![env, 10_000_u32, 10_000, 10_000, 10_000, 10_000, 12_000, 12_000], &Vec::new(env));
+    budget.check(env, "set_pricing_rules");
+
+    contract.get_pricing_rules(owner);
+    budget.check(env, "get_pricing_rules");
+
+    contract.set_discount_tiers(owner, &vec![env, DiscountTier { min_days: 7, discount_bps: 1_000 }]);
+    budget.check(env, "set_discount_tiers");
+
+    contract.get_discount_tiers(owner);
+    budget.check(env, "get_discount_tiers");
+
+    contract.set_usage_fees(owner, &100_u32, &10_i128, &20_i128);
+    budget.check(env, "set_usage_fees");
+
+    contract.get_usage_fees(owner);
+    budget.check(env, "get_usage_fees");
+
+    contract.set_car_device(owner, &BytesN::from_array(env, &device_key().verifying_key().to_bytes()));
+    budget.check(env, "set_car_device");
+
+    contract.get_car_device(owner);
+    budget.check(env, "get_car_device");
+
+    contract.set_car_location(admin, owner, &1_u32, &String::from_str(env, "Av. Central 123"));
+    budget.check(env, "set_car_location");
+
+    contract.get_car_location(owner);
+    budget.check(env, "get_car_location");
+
+    contract.list_cars_in_zone(&1_u32, &0_u32, &10_u32);
+    budget.check(env, "list_cars_in_zone");
+
+    contract.list_cars_in_category(&CarCategory::Economy, &0_u32, &10_u32);
+    budget.check(env, "list_cars_in_category");
+
+    contract.set_pickup_code_required(owner, &true);
+    budget.check(env, "set_pickup_code_required");
+}
+
+// renta completa con codigo de recogida, conductor, lecturas, reportes, dispositivo y reclamo al seguro
+fn bench_rental(test: &ContractTest, budget: &mut Budget, owner: &Address, renter: &Address) {
+    let ContractTest { env, contract, admin, .. } = test;
+    let referrer = Address::generate(env);
+    let driver = Address::generate(env);
+    let code = Bytes::from_slice(env, b"4821-KEYBOX");
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+
+    contract.register_referrer(renter, &referrer);
+    budget.check(env, "register_referrer");
+
+    contract.get_referrer(renter);
+    budget.check(env, "get_referrer");
+
+    contract.quote_rental(owner, &DAYS, &START);
+    budget.check(env, "quote_rental");
+
+    contract.rental(renter, owner, &DAYS, &RENTER_FUNDS, &None, &0, &START);
+    budget.check(env, "rental");
+
+    let id = rental_id(test, renter, owner);
+
+    contract.add_rental_driver(renter, owner, &driver);
+    budget.check(env, "add_rental_driver");
+
+    contract.accept_rental_driver(&driver, renter, owner);
+    budget.check(env, "accept_rental_driver");
+
+    contract.get_rental_drivers(renter, owner);
+    budget.check(env, "get_rental_drivers");
+
+    contract.commit_pickup_code(owner, renter, &code_hash);
+    budget.check(env, "commit_pickup_code");
+
+    contract.confirm_pickup(renter, owner, &code);
+    budget.check(env, "confirm_pickup");
+
+    contract.submit_usage_reading(renter, owner, &ReadingKind::Pickup, &1_000_u32, &90_u32);
+    budget.check(env, "submit_usage_reading");
+
+    contract.confirm_usage_reading(owner, renter, &ReadingKind::Pickup);
+    budget.check(env, "confirm_usage_reading");
+
+    contract.get_usage_reading(renter, owner, &ReadingKind::Pickup);
+    budget.check(env, "get_usage_reading");
+
+    contract.submit_condition_report(renter, owner, &ReadingKind::Pickup, &vec![env, BytesN::from_array(env, &[1; 32])]);
+    budget.check(env, "submit_condition_report");
+
+    contract.get_condition_report(&id, &ReadingKind::Pickup);
+    budget.check(env, "get_condition_report");
+
+    env.ledger().set_timestamp(START + 60);
+    let lock = sign_report(env, id, &DeviceAction::Lock, START + 60, 1);
+
+    contract.report_device_event(owner, renter, &DeviceAction::Lock, &(START + 60), &1_u64, &lock);
+    budget.check(env, "report_device_event");
+
+    // 60 km sobre lo incluido, el cargo pasa el deposito y deja deuda
+    contract.record_return_reading(owner, renter, &1_360_u32, &90_u32);
+    budget.check(env, "record_return_reading");
+
+    contract.file_insurance_claim(owner, &id, &100_i128);
+    budget.check(env, "file_insurance_claim");
+
+    contract.complete_rental(owner, renter);
+    budget.check(env, "complete_rental");
+
+    contract.get_usage_debt(renter, owner);
+    budget.check(env, "get_usage_debt");
+
+    contract.settle_usage_debt(renter, owner);
+    budget.check(env, "settle_usage_debt");
+
+    contract.get_insurance_pool();
+    budget.check(env, "get_insurance_pool");
+
+    contract.approve_insurance_claim(admin, &id, &100_i128);
+    budget.check(env, "approve_insurance_claim");
+
+    contract.get_insurance_claim(&id);
+    budget.check(env, "get_insurance_claim");
+
+    contract.get_referral_balance(&referrer);
+    budget.check(env, "get_referral_balance");
+
+    contract.claim_referral_rewards(&referrer);
+    budget.check(env, "claim_referral_rewards");
+}
+
+// segunda renta con cupon y puntos de lealtad, el reclamo se rechaza despues de la devolucion
+fn bench_discounted_rental(test: &ContractTest, budget: &mut Budget, owner: &Address, renter: &Address, code: &Bytes) {
+    let ContractTest { env, contract, admin, .. } = test;
+
+    contract.set_pickup_code_required(owner, &false);
+    contract.rental(renter, owner, &DAYS, &RENTER_FUNDS, &Some(code.clone()), &100, &START);
+    budget.check(env, "rental_with_coupon_and_points");
+
+    let id = rental_id(test, renter, owner);
+
+    contract.complete_rental(owner, renter);
+    contract.file_insurance_claim(owner, &id, &100_i128);
+    env.cost_estimate().budget().reset_default();
+
+    contract.reject_insurance_claim(admin, &id);
+    budget.check(env, "reject_insurance_claim");
+}
+
+// reparto entre co-owners sobre un carro aparte
+fn bench_co_owners(test: &ContractTest, budget: &mut Budget) {
+    let ContractTest { env, contract, admin, token, .. } = test;
+    let owner = Address::generate(env);
+    let renter = Address::generate(env);
+    let investor_a = Address::generate(env);
+    let investor_b = Address::generate(env);
+
+    token.1.mint(&renter, &RENTER_FUNDS);
+    contract.set_renter_verification(admin, &renter, &1_u32);
+    contract.add_car(admin, &owner, &PRICE_PER_DAY, &CarCategory::Economy, &None);
+    env.cost_estimate().budget().reset_default();
+
+    let co_owners = vec![
+        env,
+        CoOwner { address: investor_a.clone(), share_bps: 7_000 },
+        CoOwner { address: investor_b.clone(), share_bps: 3_000 },
+    ];
+
+    contract.set_co_owners(&owner, &co_owners);
+    budget.check(env, "set_co_owners");
+
+    contract.get_co_owners(&owner);
+    budget.check(env, "get_co_owners");
+
+    contract.rental(&renter, &owner, &DAYS, &RENTER_FUNDS, &None, &0, &START);
+    contract.complete_rental(&owner, &renter);
+    env.cost_estimate().budget().reset_default();
+
+    contract.get_co_owner_balance(&owner, &investor_a);
+    budget.check(env, "get_co_owner_balance");
+
+    contract.withdraw_co_owner_balance(&owner, &investor_a);
+    budget.check(env, "withdraw_co_owner_balance");
+}
+
+// retiros del owner, del admin y el flujo de aprobaciones
+fn bench_payouts(test: &ContractTest, budget: &mut Budget, owner: &Address, owners: &Vec<Address>) {
+    let ContractTest { env, contract, admin, .. } = test;
+
+    contract.get_available_withdraw_payowner(owner);
+    budget.check(env, "get_available_withdraw_payowner");
+
+    contract.payout_owner(owner, &(contract.get_available_withdraw_payowner(owner) / 2));
+    budget.check(env, "payout_owner");
+
+    contract.withdraw_all(owner);
+    budget.check(env, "withdraw_all");
+
+    contract.batch_payout(admin, owners);
+    budget.check(env, "batch_payout");
+
+    contract.get_admin_commission();
+    budget.check(env, "get_admin_commission");
+
+    contract.payout_admin(admin, &(contract.get_admin_commission() / 2));
+    budget.check(env, "payout_admin");
+
+    let approver = Address::generate(env);
+
+    contract.set_withdrawal_policy(admin, &vec![env, approver.clone()], &1_u32, &0_i128, &3600_u64, &86_400_u64);
+    budget.check(env, "set_withdrawal_policy");
+
+    contract.get_withdrawal_policy();
+    budget.check(env, "get_withdrawal_policy");
+
+    let id = contract.propose_withdrawal(admin, &1_i128);
+    budget.check(env, "propose_withdrawal");
+
+    contract.approve_withdrawal(&approver, &id);
+    budget.check(env, "approve_withdrawal");
+
+    contract.get_withdrawal(&id);
+    budget.check(env, "get_withdrawal");
+
+    let id = contract.propose_withdrawal(admin, &1_i128);
+    env.cost_estimate().budget().reset_default();
+
+    contract.cancel_withdrawal(admin, &id);
+    budget.check(env, "cancel_withdrawal");
+}
+
+// venta del NFT, credito del holder anterior y baja del carro
+fn bench_transfer_and_removal(test: &ContractTest, budget: &mut Budget, owner: &Address, renter: &Address) {
+    let ContractTest { env, contract, admin, .. } = test;
+    let buyer = Address::generate(env);
+    let spender = Address::generate(env);
+
+    // deja credito sin retirar para que la venta lo mueva al holder anterior
+    contract.rental(renter, owner, &DAYS, &RENTER_FUNDS, &None, &0, &START);
+    contract.complete_rental(owner, renter);
+    env.cost_estimate().budget().reset_default();
+
+    let token_id = contract.get_car_token_id(owner);
+
+    contract.transfer(owner, &buyer, &token_id);
+    budget.check(env, "transfer");
+
+    contract.get_holder_credit(owner);
+    budget.check(env, "get_holder_credit");
+
+    contract.withdraw_holder_credit(owner, &contract.get_holder_credit(owner));
+    budget.check(env, "withdraw_holder_credit");
+
+    contract.approve(&buyer, &spender, &token_id, &(env.ledger().sequence() + 100));
+    env.cost_estimate().budget().reset_default();
+
+    contract.transfer_from(&spender, &buyer, owner, &token_id);
+    budget.check(env, "transfer_from");
+
+    contract.remove_car(admin, owner);
+    budget.check(env, "remove_car");
+
+    contract.remove_zone(admin, &1_u32);
+    budget.check(env, "remove_zone");
+}
+
+// cada entrypoint se llama y se mide una vez, salvo el constructor que solo corre al desplegar
+fn run_benchmarks(fleet: u32) {
+    let (test, owners) = setup_fleet(fleet);
+    let mut budget = Budget::load(fleet);
+    let ContractTest { env, token, .. } = &test;
+
+    let owner = Address::generate(env);
+    let renter = Address::generate(env);
+    let code = Bytes::from_slice(env, b"SUMMER25");
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+
+    token.1.mint(&renter, &RENTER_FUNDS);
+    env.cost_estimate().budget().reset_default();
+
+    bench_admin(&test, &mut budget, &renter, &code_hash);
+    bench_car(&test, &mut budget, &owner);
+    bench_rental(&test, &mut budget, &owner, &renter);
+    bench_discounted_rental(&test, &mut budget, &owner, &renter, &code);
+    bench_co_owners(&test, &mut budget);
+    bench_payouts(&test, &mut budget, &owner, &owners);
+    bench_transfer_and_removal(&test, &mut budget, &owner, &renter);
+
+    budget.save();
+}
+
+#[test]
+pub fn test_budget_with_1_car() {
+    run_benchmarks(1);
+}
+
+#[test]
+pub fn test_budget_with_100_cars() {
+    run_benchmarks(100);
+}
+
+// armar la flota de 1000 carros tarda, correr con `cargo test --features budget-large-fleet`
+#[cfg(feature = "budget-large-fleet")]
+#[test]
+pub fn test_budget_with_1000_cars() {
+    run_benchmarks(1000);
+}
@@ -0,0 +1,159 @@
+use soroban_sdk::{testutils::Address as _, vec, Address};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    events::category::CategoryRulesUpdatedEvent,
+    storage::{car::read_car, rental::read_rental, structs::category::CategoryRules},
+    tests::config::{contract::ContractTest, utils::{get_contract_events, get_event_payload}},
+};
+
+#[test]
+pub fn test_add_car_indexes_by_category() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let economy = Address::generate(&env);
+    let suv = Address::generate(&env);
+    let van = Address::generate(&env);
+
+    contract.add_car(&admin, &economy, &1500_i128, &CarCategory::Economy, &None);
+    contract.add_car(&admin, &suv, &3000_i128, &CarCategory::Suv, &None);
+    contract.add_car(&admin, &van, &2500_i128, &CarCategory::Van, &None);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &suv).unwrap());
+    assert_eq!(car.category, CarCategory::Suv);
+
+    assert_eq!(contract.list_cars_in_category(&CarCategory::Economy, &0_u32, &10_u32), vec![&env, economy]);
+    assert_eq!(contract.list_cars_in_category(&CarCategory::Suv, &0_u32, &10_u32), vec![&env, suv.clone()]);
+    assert_eq!(contract.list_cars_in_category(&CarCategory::Luxury, &0_u32, &10_u32).len(), 0);
+
+    contract.remove_car(&admin, &suv);
+
+    assert_eq!(contract.list_cars_in_category(&CarCategory::Suv, &0_u32, &10_u32).len(), 0);
+}
+
+#[test]
+pub fn test_set_category_rules() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    contract.set_category_rules(&admin, &CarCategory::Luxury, &2_u32, &7_u32, &5000_i128);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let rules = CategoryRules {
+        min_verification_level: 2,
+        max_rental_days: 7,
+        deposit_floor: 5000,
+    };
+
+    assert_eq!(contract.get_category_rules(&CarCategory::Luxury), rules);
+    assert_eq!(
+        get_event_payload::<CategoryRulesUpdatedEvent>(&env, &contract_events, "category_rules_updated"),
+        CategoryRulesUpdatedEvent { category: CarCategory::Luxury, rules }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #57)")]
+pub fn test_get_category_rules_not_set_fails() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    contract.get_category_rules(&CarCategory::Van);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #54)")]
+pub fn test_set_category_rules_with_negative_deposit_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    contract.set_category_rules(&admin, &CarCategory::Luxury, &0_u32, &0_u32, &-1_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #55)")]
+pub fn test_rental_with_low_verification_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &100_000_i128);
+
+    contract.add_car(&admin, &owner, &10_000_i128, &CarCategory::Luxury, &None);
+    contract.set_category_rules(&admin, &CarCategory::Luxury, &2_u32, &0_u32, &0_i128);
+    contract.set_renter_verification(&admin, &renter, &1_u32);
+
+    contract.rental(&renter, &owner, &1_u32, &10_000_i128, &None, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #56)")]
+pub fn test_rental_longer_than_category_max_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &100_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Van, &None);
+    contract.set_category_rules(&admin, &CarCategory::Van, &0_u32, &3_u32, &0_i128);
+
+    contract.rental(&renter, &owner, &4_u32, &6000_i128, &None, &0, &0);
+}
+
+#[test]
+pub fn test_rental_holds_category_deposit_until_completed() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 10_000_i128;
+    let deposit = 5000_i128;
+
+    token_admin.mint(&renter, &100_000_i128);
+
+    contract.add_car(&admin, &owner, &price_per_day, &CarCategory::Luxury, &None);
+    contract.set_category_rules(&admin, &CarCategory::Luxury, &2_u32, &7_u32, &deposit);
+    contract.set_renter_verification(&admin, &renter, &2_u32);
+    assert_eq!(contract.get_renter_verification(&renter), 2);
+
+    contract.rental(&renter, &owner, &2_u32, &(price_per_day * 2 + deposit), &None, &0, &0);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner).unwrap());
+    assert_eq!(rental.deposit, deposit);
+    assert_eq!(rental.amount, price_per_day * 2 + deposit);
+    assert_eq!(token_client.balance(&renter), 100_000 - price_per_day * 2 - deposit);
+
+    // el deposito no se le acredita al owner
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
+    assert_eq!(car.available_to_withdraw, price_per_day * 2 - (price_per_day * 2 / 100) * 2);
+
+    contract.complete_rental(&owner, &renter);
+
+    assert_eq!(token_client.balance(&renter), 100_000 - price_per_day * 2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+pub fn test_rental_amount_must_cover_category_deposit() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &100_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Suv, &None);
+    contract.set_category_rules(&admin, &CarCategory::Suv, &0_u32, &0_u32, &1000_i128);
+
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+}
\ No newline at end of file
@@ -0,0 +1,107 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, vec, Vec};
+use crate::storage::types::car_category::CarCategory;
+use crate::{storage::structs::co_owner::CoOwner, tests::config::contract::ContractTest};
+
+fn co_owners_table(env: &Env, first: &Address, second: &Address) -> Vec<CoOwner> {
+    vec![
+        env,
+        CoOwner { address: first.clone(), share_bps: 7_000 },
+        CoOwner { address: second.clone(), share_bps: 3_000 },
+    ]
+}
+
+#[test]
+pub fn test_rental_credit_is_split_between_co_owners() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let investor_a = Address::generate(&env);
+    let investor_b = Address::generate(&env);
+    let amount = 4500_i128;
+    let net = amount - (amount/100)*2;
+
+    token.1.mint(&renter, &amount);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_co_owners(&owner, &co_owners_table(&env, &investor_a, &investor_b));
+
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+
+    let share_b = net * 3_000 / 10_000;
+
+    assert_eq!(contract.get_co_owner_balance(&owner, &investor_a), net - share_b);
+    assert_eq!(contract.get_co_owner_balance(&owner, &investor_b), share_b);
+    assert_eq!(contract.get_available_withdraw_payowner(&owner), 0);
+
+    contract.complete_rental(&owner, &renter);
+
+    let withdrawn = contract.withdraw_co_owner_balance(&owner, &investor_b);
+
+    assert_eq!(withdrawn, share_b);
+    assert_eq!(token.0.balance(&investor_b), share_b);
+    assert_eq!(contract.get_co_owner_balance(&owner, &investor_b), 0);
+    assert_eq!(contract.get_co_owner_balance(&owner, &investor_a), net - share_b);
+}
+
+#[test]
+pub fn test_co_owners_can_update_table_together() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let investor_a = Address::generate(&env);
+    let investor_b = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_co_owners(&owner, &co_owners_table(&env, &investor_a, &investor_b));
+
+    let updated = co_owners_table(&env, &investor_b, &investor_a);
+    contract.set_co_owners(&owner, &updated);
+
+    assert_eq!(contract.get_co_owners(&owner), updated);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #43)")]
+pub fn test_set_co_owners_with_shares_not_summing_to_total_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_co_owners(&owner, &vec![
+        &env,
+        CoOwner { address: Address::generate(&env), share_bps: 5_000 },
+        CoOwner { address: Address::generate(&env), share_bps: 4_000 },
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #43)")]
+pub fn test_set_co_owners_with_duplicate_address_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_co_owners(&owner, &co_owners_table(&env, &investor, &investor));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #38)")]
+pub fn test_withdraw_co_owner_balance_without_balance_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let investor_a = Address::generate(&env);
+    let investor_b = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_co_owners(&owner, &co_owners_table(&env, &investor_a, &investor_b));
+    contract.withdraw_co_owner_balance(&owner, &investor_a);
+}
@@ -0,0 +1,94 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    events::condition::ConditionReportedEvent,
+    storage::types::reading_kind::ReadingKind,
+    tests::config::{contract::ContractTest, utils::{get_contract_events, get_event_payload}},
+};
+
+fn photo(env: &Env, n: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[n; 32])
+}
+
+#[test]
+pub fn test_condition_reports_are_kept_after_rental() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    let pickup = vec![&env, photo(&env, 1), photo(&env, 2)];
+    contract.submit_condition_report(&renter, &owner, &ReadingKind::Pickup, &pickup);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        get_event_payload::<ConditionReportedEvent>(&env, &contract_events, "condition_reported"),
+        ConditionReportedEvent {
+            rental_id: 0,
+            kind: ReadingKind::Pickup,
+            renter: renter.clone(),
+            holder: owner.clone(),
+            evidence: pickup.clone(),
+        }
+    );
+
+    contract.submit_condition_report(&renter, &owner, &ReadingKind::Return, &vec![&env, photo(&env, 3)]);
+    contract.complete_rental(&owner, &renter);
+
+    let report = contract.get_condition_report(&0_u32, &ReadingKind::Pickup);
+    assert_eq!(report.evidence, pickup);
+    assert_eq!(report.renter, renter);
+    assert_eq!(contract.get_condition_report(&0_u32, &ReadingKind::Return).evidence.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #64)")]
+pub fn test_condition_report_cannot_be_replaced() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.submit_condition_report(&renter, &owner, &ReadingKind::Pickup, &vec![&env, photo(&env, 1)]);
+    contract.submit_condition_report(&renter, &owner, &ReadingKind::Pickup, &vec![&env, photo(&env, 2)]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #63)")]
+pub fn test_condition_report_with_duplicated_evidence_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.submit_condition_report(&renter, &owner, &ReadingKind::Pickup, &vec![&env, photo(&env, 1), photo(&env, 1)]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #65)")]
+pub fn test_get_missing_condition_report_fails() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    contract.get_condition_report(&0_u32, &ReadingKind::Return);
+}
\ No newline at end of file
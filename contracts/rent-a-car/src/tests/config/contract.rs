@@ -1,4 +1,5 @@
 use soroban_sdk::{testutils::Address as _, token, Address, Env};
+use loyalty_token::{LoyaltyToken, LoyaltyTokenClient};
 use crate::{contract::RentACarContractClient, RentACarContract};
 use crate::tests::config::utils::create_token_contract;
 
@@ -7,6 +8,7 @@ pub struct ContractTest<'a> {
     pub contract: RentACarContractClient<'a>,
     pub admin: Address,
     pub token: (token::Client<'a>, token::StellarAssetClient<'a>, Address),
+    pub loyalty_token: (LoyaltyTokenClient<'a>, Address),
 }
 
 impl<'a> ContractTest<'a> {
@@ -18,7 +20,14 @@ impl<'a> ContractTest<'a> {
 
         let (token_client, token_admin) = create_token_contract(&env, &token_issuer);
 
-        let contract_id = env.register(RentACarContract, (&admin, &token_client.address)); // this deploys the contrat
+        let contract_id = Address::generate(&env);
+
+        // el contrato queda como owner del token de lealtad para poder mintear puntos,
+        // se despliega antes para que el ultimo evento siga siendo contract_initialized
+        let loyalty_id = env.register(LoyaltyToken, (&contract_id,));
+        let loyalty_client = LoyaltyTokenClient::new(&env, &loyalty_id);
+
+        env.register_at(&contract_id, RentACarContract, (&admin, &token_client.address)); // this deploys the contrat
         let contract = RentACarContractClient::new(&env, &contract_id);
 
         ContractTest {
@@ -26,6 +35,7 @@ impl<'a> ContractTest<'a> {
             contract,
             admin,
             token: (token_client, token_admin, token_issuer),
+            loyalty_token: (loyalty_client, loyalty_id),
         }
     }
-}
\ No newline at end of file
+}
@@ -1,6 +1,8 @@
-use soroban_sdk::{token, Address, Env, Vec, Val};
+use soroban_sdk::{token, Address, Env, Symbol, TryFromVal, Vec, Val};
 use soroban_sdk::testutils::Events;
 
+use crate::events::EVENT_VERSION;
+
 pub(crate) fn create_token_contract<'a>(
     e: &Env,
     admin: &Address,
@@ -26,4 +28,29 @@ pub(crate) fn get_contract_events(
         .for_each(|event| contract_events.push_back(event));
 
     contract_events
+}
+
+// busca el ultimo evento `name` en los eventos del contrato, valida la version
+// del schema y decodifica su payload tipado
+pub(crate) fn get_event_payload<T>(
+    env: &Env,
+    contract_events: &Vec<(Address, Vec<Val>, Val)>,
+    name: &str,
+) -> T
+where
+    T: TryFromVal<Env, Val>,
+{
+    let event = contract_events
+        .iter()
+        .filter(|event| {
+            event.1.get(0).and_then(|topic| Symbol::try_from_val(env, &topic).ok())
+                == Some(Symbol::new(env, name))
+        })
+        .last()
+        .expect("event not found");
+
+    let version = event.1.get(1).and_then(|topic| u32::try_from_val(env, &topic).ok());
+    assert_eq!(version, Some(EVENT_VERSION));
+
+    T::try_from_val(env, &event.2).unwrap()
 }
\ No newline at end of file
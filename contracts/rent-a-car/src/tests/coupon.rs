@@ -0,0 +1,163 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Bytes, BytesN, Env};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    storage::{
+        admin::read_commission, contract_balance::read_contract_balance, rental::read_rental,
+        types::coupon_discount::CouponDiscount,
+    },
+    tests::config::contract::ContractTest,
+};
+
+fn promo_code(env: &Env) -> (Bytes, BytesN<32>) {
+    let code = Bytes::from_slice(env, b"SUMMER25");
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+
+    (code, code_hash)
+}
+
+// una renta previa de 50_000 deja 1_000 de comision para subsidiar cupones
+fn accrue_commission(test: &ContractTest) {
+    let ContractTest { env, contract, admin, token, .. } = test;
+
+    let owner = Address::generate(env);
+    let renter = Address::generate(env);
+
+    token.1.mint(&renter, &50_000_i128);
+    contract.add_car(admin, &owner, &10_000_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &5_u32, &50_000_i128, &None, &0, &0);
+}
+
+#[test]
+pub fn test_rental_with_coupon_is_subsidized_by_commission() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    accrue_commission(&test);
+    let ContractTest { env, contract, admin, token, .. } = test;
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (code, code_hash) = promo_code(&env);
+    let expires_at = env.ledger().timestamp() + 3600;
+
+    token.1.mint(&renter, &3_000_i128);
+    contract.add_car(&admin, &owner, &1_000_i128, &CarCategory::Economy, &None);
+    contract.create_coupon(&admin, &code_hash, &CouponDiscount::Bps(1_000), &10_u32, &1_u32, &expires_at);
+
+    contract.rental(&renter, &owner, &3_u32, &3_000_i128, &Some(code), &0, &0);
+
+    // el renter paga 3_000 - 10%, el owner recibe lo mismo que sin cupon
+    assert_eq!(token.0.balance(&renter), 300);
+    assert_eq!(contract.get_available_withdraw_payowner(&owner), 3_000 - 60);
+    assert_eq!(env.as_contract(&contract.address, || read_commission(&env)), 1_000 + 60 - 300);
+    assert_eq!(
+        env.as_contract(&contract.address, || read_contract_balance(&env)),
+        (50_000 - 1_000) + (3_000 - 60)
+    );
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner).unwrap());
+    assert_eq!(rental.amount, 2_700);
+    assert_eq!(rental.coupon_discount, 300);
+    assert_eq!(contract.get_coupon(&code_hash).uses_remaining, 9);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+pub fn test_coupon_per_address_limit() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    accrue_commission(&test);
+    let ContractTest { env, contract, admin, token, .. } = test;
+
+    let owner_1 = Address::generate(&env);
+    let owner_2 = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (code, code_hash) = promo_code(&env);
+    let expires_at = env.ledger().timestamp() + 3600;
+
+    token.1.mint(&renter, &6_000_i128);
+    contract.add_car(&admin, &owner_1, &1_000_i128, &CarCategory::Economy, &None);
+    contract.add_car(&admin, &owner_2, &1_000_i128, &CarCategory::Economy, &None);
+    contract.create_coupon(&admin, &code_hash, &CouponDiscount::Fixed(100), &10_u32, &1_u32, &expires_at);
+
+    contract.rental(&renter, &owner_1, &3_u32, &3_000_i128, &Some(code.clone()), &0, &0);
+    contract.rental(&renter, &owner_2, &3_u32, &3_000_i128, &Some(code), &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #31)")]
+pub fn test_coupon_usage_cap() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    accrue_commission(&test);
+    let ContractTest { env, contract, admin, token, .. } = test;
+
+    let owner_1 = Address::generate(&env);
+    let owner_2 = Address::generate(&env);
+    let renter_1 = Address::generate(&env);
+    let renter_2 = Address::generate(&env);
+    let (code, code_hash) = promo_code(&env);
+    let expires_at = env.ledger().timestamp() + 3600;
+
+    token.1.mint(&renter_1, &3_000_i128);
+    token.1.mint(&renter_2, &3_000_i128);
+    contract.add_car(&admin, &owner_1, &1_000_i128, &CarCategory::Economy, &None);
+    contract.add_car(&admin, &owner_2, &1_000_i128, &CarCategory::Economy, &None);
+    contract.create_coupon(&admin, &code_hash, &CouponDiscount::Fixed(100), &1_u32, &1_u32, &expires_at);
+
+    contract.rental(&renter_1, &owner_1, &3_u32, &3_000_i128, &Some(code.clone()), &0, &0);
+    contract.rental(&renter_2, &owner_2, &3_u32, &3_000_i128, &Some(code), &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")]
+pub fn test_expired_coupon_fails() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    accrue_commission(&test);
+    let ContractTest { env, contract, admin, token, .. } = test;
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (code, code_hash) = promo_code(&env);
+    let expires_at = env.ledger().timestamp() + 3600;
+
+    token.1.mint(&renter, &3_000_i128);
+    contract.add_car(&admin, &owner, &1_000_i128, &CarCategory::Economy, &None);
+    contract.create_coupon(&admin, &code_hash, &CouponDiscount::Fixed(100), &10_u32, &1_u32, &expires_at);
+
+    env.ledger().set_timestamp(expires_at);
+    contract.rental(&renter, &owner, &3_u32, &3_000_i128, &Some(code), &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")]
+pub fn test_unknown_coupon_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token.1.mint(&renter, &3_000_i128);
+    contract.add_car(&admin, &owner, &1_000_i128, &CarCategory::Economy, &None);
+
+    contract.rental(&renter, &owner, &3_u32, &3_000_i128, &Some(Bytes::from_slice(&env, b"NOPE")), &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_coupon_subsidy_cannot_exceed_commission() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (code, code_hash) = promo_code(&env);
+    let expires_at = env.ledger().timestamp() + 3600;
+
+    token.1.mint(&renter, &3_000_i128);
+    contract.add_car(&admin, &owner, &1_000_i128, &CarCategory::Economy, &None);
+    contract.create_coupon(&admin, &code_hash, &CouponDiscount::Bps(1_000), &10_u32, &1_u32, &expires_at);
+
+    contract.rental(&renter, &owner, &3_u32, &3_000_i128, &Some(code), &0, &0);
+}
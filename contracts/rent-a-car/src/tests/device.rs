@@ -0,0 +1,126 @@
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, BytesN, Env};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    events::device::DeviceReportEvent,
+    methods::device::device::device_report_message,
+    storage::{rental::read_rental, types::device_action::DeviceAction},
+    tests::config::{contract::ContractTest, utils::{get_contract_events, get_event_payload}},
+};
+
+fn device_key() -> SigningKey {
+    SigningKey::from_bytes(&[7; 32])
+}
+
+fn sign_report(env: &Env, key: &SigningKey, rental_id: u32, action: &DeviceAction, timestamp: u64, nonce: u64) -> BytesN<64> {
+    let mut message = [0u8; 24];
+    device_report_message(env, rental_id, action, timestamp, nonce).copy_into_slice(&mut message);
+
+    BytesN::from_array(env, &key.sign(&message).to_bytes())
+}
+
+fn setup_rented_car_with_device(test: &ContractTest) -> (Address, Address) {
+    let ContractTest { env, contract, admin, token, .. } = test;
+    env.mock_all_auths();
+    env.ledger().set_timestamp(10_000);
+
+    let owner = Address::generate(env);
+    let renter = Address::generate(env);
+
+    token.1.mint(&renter, &10_000_i128);
+
+    contract.add_car(admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_car_device(&owner, &BytesN::from_array(env, &device_key().verifying_key().to_bytes()));
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    (owner, renter)
+}
+
+#[test]
+pub fn test_device_reports_mark_rental_start_and_end() {
+    let test = ContractTest::setup();
+    let (owner, renter) = setup_rented_car_with_device(&test);
+    let ContractTest { env, contract, .. } = &test;
+    let key = device_key();
+
+    let unlock = sign_report(env, &key, 0, &DeviceAction::Unlock, 9_900, 1);
+    contract.report_device_event(&owner, &renter, &DeviceAction::Unlock, &9_900_u64, &1_u64, &unlock);
+    let contract_events = get_contract_events(env, &contract.address);
+
+    assert_eq!(
+        get_event_payload::<DeviceReportEvent>(env, &contract_events, "device_report"),
+        DeviceReportEvent { car_id: contract.get_car_token_id(&owner), rental_id: 0, action: DeviceAction::Unlock, timestamp: 9_900, nonce: 1 }
+    );
+
+    let lock = sign_report(env, &key, 0, &DeviceAction::Lock, 9_950, 2);
+    contract.report_device_event(&owner, &renter, &DeviceAction::Lock, &9_950_u64, &2_u64, &lock);
+
+    let rental = env.as_contract(&contract.address, || read_rental(env, &renter, &owner).unwrap());
+    assert_eq!(rental.started_at, Some(9_900));
+    assert_eq!(rental.ended_at, Some(9_950));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #67)")]
+pub fn test_replayed_device_report_fails() {
+    let test = ContractTest::setup();
+    let (owner, renter) = setup_rented_car_with_device(&test);
+    let ContractTest { env, contract, .. } = &test;
+
+    let unlock = sign_report(env, &device_key(), 0, &DeviceAction::Unlock, 9_900, 1);
+    contract.report_device_event(&owner, &renter, &DeviceAction::Unlock, &9_900_u64, &1_u64, &unlock);
+    contract.report_device_event(&owner, &renter, &DeviceAction::Unlock, &9_900_u64, &1_u64, &unlock);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #67)")]
+pub fn test_old_device_report_fails() {
+    let test = ContractTest::setup();
+    let (owner, renter) = setup_rented_car_with_device(&test);
+    let ContractTest { env, contract, .. } = &test;
+
+    let unlock = sign_report(env, &device_key(), 0, &DeviceAction::Unlock, 1_000, 1);
+    contract.report_device_event(&owner, &renter, &DeviceAction::Unlock, &1_000_u64, &1_u64, &unlock);
+}
+
+#[test]
+#[should_panic]
+pub fn test_device_report_signed_for_other_action_fails() {
+    let test = ContractTest::setup();
+    let (owner, renter) = setup_rented_car_with_device(&test);
+    let ContractTest { env, contract, .. } = &test;
+
+    // la firma de un unlock no sirve como lock
+    let unlock = sign_report(env, &device_key(), 0, &DeviceAction::Unlock, 9_900, 1);
+    contract.report_device_event(&owner, &renter, &DeviceAction::Lock, &9_900_u64, &1_u64, &unlock);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #68)")]
+pub fn test_lock_before_unlock_fails() {
+    let test = ContractTest::setup();
+    let (owner, renter) = setup_rented_car_with_device(&test);
+    let ContractTest { env, contract, .. } = &test;
+
+    let lock = sign_report(env, &device_key(), 0, &DeviceAction::Lock, 9_900, 1);
+    contract.report_device_event(&owner, &renter, &DeviceAction::Lock, &9_900_u64, &1_u64, &lock);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #66)")]
+pub fn test_device_report_without_registered_device_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    let signature = BytesN::from_array(&env, &[0; 64]);
+    contract.report_device_event(&owner, &renter, &DeviceAction::Unlock, &0_u64, &1_u64, &signature);
+}
\ No newline at end of file
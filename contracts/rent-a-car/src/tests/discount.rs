@@ -0,0 +1,79 @@
+use soroban_sdk::{testutils::Address as _, vec, Address};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    events::rental::RentedEvent,
+    storage::{rental::read_rental, structs::pricing::DiscountTier},
+    tests::config::contract::ContractTest,
+};
+use crate::tests::config::utils::{get_contract_events, get_event_payload};
+
+#[test]
+pub fn test_rental_applies_best_discount_tier() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let total_days = 30_u32;
+    let tiers = vec![
+        &env,
+        DiscountTier { min_days: 7, discount_bps: 1_000 },
+        DiscountTier { min_days: 30, discount_bps: 2_500 },
+    ];
+
+    token.1.mint(&renter, &30_000_i128);
+    contract.add_car(&admin, &owner, &1_000_i128, &CarCategory::Economy, &None);
+    contract.set_discount_tiers(&owner, &tiers);
+
+    // 30 dias * 1_000 con -25%
+    assert_eq!(contract.quote_rental(&owner, &total_days, &0), 22_500);
+
+    contract.rental(&renter, &owner, &total_days, &30_000_i128, &None, &0, &0);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner).unwrap());
+    assert_eq!(rental.amount, 22_500);
+    assert_eq!(rental.discount, 7_500);
+    assert_eq!(token.0.balance(&renter), 7_500);
+    assert_eq!(
+        get_event_payload::<RentedEvent>(&env, &contract_events, "rented"),
+        RentedEvent {
+            renter: renter.clone(),
+            owner: owner.clone(),
+            car_id: contract.get_car_token_id(&owner),
+            rental_id: rental.id,
+            total_days,
+            amount: 22_500,
+            discount: 7_500,
+        }
+    );
+}
+
+#[test]
+pub fn test_short_rental_gets_no_discount() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let tiers = vec![&env, DiscountTier { min_days: 7, discount_bps: 1_000 }];
+
+    contract.add_car(&admin, &owner, &1_000_i128, &CarCategory::Economy, &None);
+    contract.set_discount_tiers(&owner, &tiers);
+
+    assert_eq!(contract.get_discount_tiers(&owner).len(), 1);
+    assert_eq!(contract.quote_rental(&owner, &6_u32, &0), 6_000);
+    assert_eq!(contract.quote_rental(&owner, &7_u32, &0), 6_300);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+pub fn test_set_discount_tiers_with_full_discount_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let tiers = vec![&env, DiscountTier { min_days: 7, discount_bps: 10_000 }];
+
+    contract.add_car(&admin, &owner, &1_000_i128, &CarCategory::Economy, &None);
+    contract.set_discount_tiers(&owner, &tiers);
+}
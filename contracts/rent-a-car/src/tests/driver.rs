@@ -0,0 +1,206 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, BytesN};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    events::driver::DriverInvitedEvent,
+    storage::{car::read_car, contract_balance::read_contract_balance},
+    tests::config::{contract::ContractTest, utils::{get_contract_events, get_event_payload}},
+};
+
+#[test]
+pub fn test_accepted_driver_is_listed_and_fee_goes_to_owner() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let driver = Address::generate(&env);
+    let credit = 3000 - (3000 / 100) * 2;
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_driver_fee(&owner, &200_i128);
+    contract.rental(&renter, &owner, &2_u32, &3000_i128, &None, &0, &0);
+
+    contract.add_rental_driver(&renter, &owner, &driver);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        get_event_payload::<DriverInvitedEvent>(&env, &contract_events, "driver_invited"),
+        DriverInvitedEvent { renter: renter.clone(), driver: driver.clone(), rental_id: 0, fee: 400 }
+    );
+    assert_eq!(token_client.balance(&renter), 10_000 - 3000 - 400);
+
+    // hasta que acepte no puede manejar
+    assert_eq!(contract.get_rental_drivers(&renter, &owner).len(), 0);
+
+    contract.accept_rental_driver(&driver, &renter, &owner);
+
+    assert_eq!(contract.get_rental_drivers(&renter, &owner), vec![&env, driver]);
+
+    // el cargo queda retenido hasta que la renta arranca o se completa
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
+    assert_eq!(car.available_to_withdraw, credit);
+    assert_eq!(env.as_contract(&contract.address, || read_contract_balance(&env)), credit);
+
+    contract.complete_rental(&owner, &renter);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
+    assert_eq!(car.available_to_withdraw, credit + 400);
+    assert_eq!(env.as_contract(&contract.address, || read_contract_balance(&env)), credit + 400);
+}
+
+#[test]
+pub fn test_accepted_driver_fee_is_released_on_pickup() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let driver = Address::generate(&env);
+    let code = Bytes::from_slice(&env, b"4821-KEYBOX");
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+    let credit = 3000 - (3000 / 100) * 2;
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_driver_fee(&owner, &200_i128);
+    contract.set_pickup_code_required(&owner, &true);
+    contract.rental(&renter, &owner, &2_u32, &3000_i128, &None, &0, &0);
+    contract.add_rental_driver(&renter, &owner, &driver);
+    contract.accept_rental_driver(&driver, &renter, &owner);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
+    assert_eq!(car.available_to_withdraw, 0);
+
+    contract.commit_pickup_code(&owner, &renter, &code_hash);
+    contract.confirm_pickup(&renter, &owner, &code);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
+    assert_eq!(car.available_to_withdraw, credit + 400);
+    assert_eq!(env.as_contract(&contract.address, || read_contract_balance(&env)), credit + 400);
+}
+
+#[test]
+pub fn test_accepted_driver_fee_is_refunded_on_no_show() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let driver = Address::generate(&env);
+    let commission = (3000 / 100) * 2;
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_driver_fee(&owner, &200_i128);
+    contract.set_pickup_code_required(&owner, &true);
+    contract.rental(&renter, &owner, &2_u32, &3000_i128, &None, &0, &0);
+    contract.add_rental_driver(&renter, &owner, &driver);
+    contract.accept_rental_driver(&driver, &renter, &owner);
+
+    // el renter nunca recoge el carro, se le devuelve la renta sin la comision y el cargo del conductor
+    contract.complete_rental(&owner, &renter);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - commission);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
+    assert_eq!(car.available_to_withdraw, 0);
+    assert_eq!(env.as_contract(&contract.address, || read_contract_balance(&env)), 0);
+}
+
+#[test]
+pub fn test_unaccepted_driver_fee_is_refunded_on_completion() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_driver_fee(&owner, &200_i128);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+    contract.add_rental_driver(&renter, &owner, &Address::generate(&env));
+
+    contract.complete_rental(&owner, &renter);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - 1500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #72)")]
+pub fn test_add_too_many_drivers_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    for _ in 0..4 {
+        contract.add_rental_driver(&renter, &owner, &Address::generate(&env));
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #73)")]
+pub fn test_add_same_driver_twice_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let driver = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.add_rental_driver(&renter, &owner, &driver);
+    contract.add_rental_driver(&renter, &owner, &driver);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #74)")]
+pub fn test_accept_without_invite_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.accept_rental_driver(&Address::generate(&env), &renter, &owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #75)")]
+pub fn test_negative_driver_fee_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_driver_fee(&owner, &-1_i128);
+}
\ No newline at end of file
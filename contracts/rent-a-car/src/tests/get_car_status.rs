@@ -1,16 +1,17 @@
 use soroban_sdk::{testutils::Address as _, Address};
+use crate::storage::types::car_category::CarCategory;
 use crate::{storage::{types::car_status::CarStatus}, storage::car::{has_car}, tests::config::contract::ContractTest};
 
 #[test]
 pub fn test_get_car_status_returns_available() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
 
     env.mock_all_auths();
 
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
 
-    contract.add_car(&owner, &price_per_day);
+    contract.add_car(&admin, &owner, &price_per_day, &CarCategory::Economy, &None);
     let is_car_stored = env.as_contract(&contract.address, || {
         has_car(&env, &owner)
     });
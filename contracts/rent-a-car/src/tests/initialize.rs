@@ -1,5 +1,5 @@
-use soroban_sdk::{vec, Symbol, IntoVal};
-use crate::tests::config::{contract::ContractTest, utils::get_contract_events};
+use crate::events::contract::ContractInitializedEvent;
+use crate::tests::config::{contract::ContractTest, utils::{get_contract_events, get_event_payload}};
 
 #[test]
 pub fn test_initialize() {
@@ -9,18 +9,9 @@ pub fn test_initialize() {
     let contract_admin = contract.get_admin();
 
     assert_eq!(admin, contract_admin);
+    assert_eq!(contract_events.len(), 1);
     assert_eq!(
-        contract_events,
-        vec![
-            &env,
-            (
-                contract.address.clone(),
-                vec![
-                    &env,
-                    *Symbol::new(&env, "contract_initialized").as_val(),
-                ],
-                (admin.clone(), token.0.address.clone()).into_val(&env)
-            )
-        ]
+        get_event_payload::<ContractInitializedEvent>(&env, &contract_events, "contract_initialized"),
+        ContractInitializedEvent { admin: admin.clone(), token: token.0.address.clone() }
     );
 }
\ No newline at end of file
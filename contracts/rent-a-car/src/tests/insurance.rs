@@ -0,0 +1,260 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    storage::types::{claim_status::ClaimStatus, role::Role},
+    tests::config::contract::ContractTest,
+};
+
+const CLAIM_WINDOW: u64 = 7 * 86_400;
+
+#[test]
+pub fn test_rental_diverts_premium_to_insurance_pool() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price = 4500_i128;
+    let premium = price * 500 / 10_000;
+
+    token.1.mint(&renter, &(price + premium));
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_insurance_config(&admin, &500_u32, &1000_i128, &CLAIM_WINDOW);
+
+    contract.rental(&renter, &owner, &3_u32, &(price + premium), &None, &0, &0);
+
+    assert_eq!(contract.get_insurance_pool(), premium);
+    assert_eq!(token.0.balance(&renter), 0);
+    assert_eq!(contract.get_admin_commission(), (price/100)*2);
+    assert_eq!(contract.get_available_withdraw_payowner(&owner), price - (price/100)*2);
+}
+
+#[test]
+pub fn test_arbiter_approves_claim_from_pool() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let price = 4500_i128;
+    let premium = price * 500 / 10_000;
+
+    token.1.mint(&renter, &(price + premium));
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_insurance_config(&admin, &500_u32, &1000_i128, &CLAIM_WINDOW);
+    contract.grant_role(&admin, &arbiter, &Role::Arbiter);
+    contract.rental(&renter, &owner, &3_u32, &(price + premium), &None, &0, &0);
+
+    contract.file_insurance_claim(&owner, &0_u32, &200_i128);
+    contract.approve_insurance_claim(&arbiter, &0_u32, &150_i128);
+
+    let claim = contract.get_insurance_claim(&0_u32);
+
+    assert_eq!(claim.status, ClaimStatus::Approved);
+    assert_eq!(claim.paid, 150_i128);
+    assert_eq!(token.0.balance(&owner), 150_i128);
+    assert_eq!(contract.get_insurance_pool(), premium - 150);
+}
+
+#[test]
+pub fn test_rejected_claim_keeps_pool() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price = 4500_i128;
+    let premium = price * 500 / 10_000;
+
+    token.1.mint(&renter, &(price + premium));
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_insurance_config(&admin, &500_u32, &1000_i128, &CLAIM_WINDOW);
+    contract.rental(&renter, &owner, &3_u32, &(price + premium), &None, &0, &0);
+
+    contract.file_insurance_claim(&owner, &0_u32, &200_i128);
+    contract.reject_insurance_claim(&admin, &0_u32);
+
+    assert_eq!(contract.get_insurance_claim(&0_u32).status, ClaimStatus::Rejected);
+    assert_eq!(contract.get_insurance_pool(), premium);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #48)")]
+pub fn test_claim_above_cap_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4725_i128;
+
+    token.1.mint(&renter, &amount);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_insurance_config(&admin, &500_u32, &1000_i128, &CLAIM_WINDOW);
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+
+    contract.file_insurance_claim(&owner, &0_u32, &1001_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #49)")]
+pub fn test_approve_claim_above_pool_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4725_i128;
+
+    token.1.mint(&renter, &amount);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_insurance_config(&admin, &500_u32, &1000_i128, &CLAIM_WINDOW);
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+
+    contract.file_insurance_claim(&owner, &0_u32, &500_i128);
+    contract.approve_insurance_claim(&admin, &0_u32, &500_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+pub fn test_claim_without_rental_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_insurance_config(&admin, &500_u32, &1000_i128, &CLAIM_WINDOW);
+
+    contract.file_insurance_claim(&owner, &0_u32, &100_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+pub fn test_rental_without_premium_in_amount_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4500_i128;
+
+    token.1.mint(&renter, &amount);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_insurance_config(&admin, &500_u32, &1000_i128, &CLAIM_WINDOW);
+
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+}
+
+#[test]
+pub fn test_quote_includes_insurance_premium() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price = 4500_i128;
+    let premium = price * 500 / 10_000;
+
+    token.1.mint(&renter, &(price + premium));
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_insurance_config(&admin, &500_u32, &1000_i128, &CLAIM_WINDOW);
+
+    let quote = contract.quote_rental(&owner, &3_u32, &0);
+    assert_eq!(quote, price + premium);
+
+    contract.rental(&renter, &owner, &3_u32, &quote, &None, &0, &0);
+    assert_eq!(contract.get_insurance_pool(), premium);
+}
+
+#[test]
+pub fn test_claim_after_return_within_window() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4725_i128;
+
+    token.1.mint(&renter, &amount);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_insurance_config(&admin, &500_u32, &1000_i128, &CLAIM_WINDOW);
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+    contract.complete_rental(&owner, &renter);
+
+    // los danos se descubren despues de la devolucion
+    env.ledger().set_timestamp(env.ledger().timestamp() + CLAIM_WINDOW);
+    contract.file_insurance_claim(&owner, &0_u32, &200_i128);
+
+    let claim = contract.get_insurance_claim(&0_u32);
+
+    assert_eq!(claim.rental_id, 0);
+    assert_eq!(claim.renter, renter);
+    assert_eq!(claim.status, ClaimStatus::Pending);
+}
+
+#[test]
+pub fn test_claim_after_car_is_removed() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4725_i128;
+
+    token.1.mint(&renter, &amount);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_insurance_config(&admin, &500_u32, &1000_i128, &CLAIM_WINDOW);
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+    contract.complete_rental(&owner, &renter);
+    contract.remove_car(&owner, &owner);
+
+    // el carro ya no esta listado, la renta asegurada sigue cubierta
+    contract.file_insurance_claim(&owner, &0_u32, &200_i128);
+
+    let claim = contract.get_insurance_claim(&0_u32);
+
+    assert_eq!(claim.claimant, owner);
+    assert_eq!(claim.renter, renter);
+    assert_eq!(claim.status, ClaimStatus::Pending);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #80)")]
+pub fn test_claim_after_window_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4725_i128;
+
+    token.1.mint(&renter, &amount);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_insurance_config(&admin, &500_u32, &1000_i128, &CLAIM_WINDOW);
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+    contract.complete_rental(&owner, &renter);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + CLAIM_WINDOW + 1);
+    contract.file_insurance_claim(&owner, &0_u32, &200_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #81)")]
+pub fn test_second_claim_for_same_rental_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4725_i128;
+
+    token.1.mint(&renter, &amount);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_insurance_config(&admin, &500_u32, &1000_i128, &CLAIM_WINDOW);
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+
+    contract.file_insurance_claim(&owner, &0_u32, &200_i128);
+    contract.file_insurance_claim(&owner, &0_u32, &100_i128);
+}
\ No newline at end of file
@@ -0,0 +1,216 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    storage::{
+        admin::read_commission, car::{read_car, read_held_holder_credit, read_holder_credit}, contract_balance::read_contract_balance,
+        insurance::read_insurance_pool, referral::read_referral_balance, rental::read_rental,
+        types::car_status::CarStatus,
+    },
+    tests::config::contract::ContractTest,
+};
+
+const SEEDS: u64 = 16;
+const STEPS: u32 = 120;
+const OWNERS: usize = 4;
+const RENTERS: usize = 4;
+const ACTORS: usize = OWNERS + RENTERS + 1;
+const ACTOR_FUNDS: i128 = 10_000_000;
+const DEPOSIT: i128 = 250;
+const DRIVER_FEE_PER_DAY: i128 = 40;
+
+// xorshift64, suficiente para generar secuencias reproducibles sin dependencias extra
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, max: u64) -> u64 {
+        self.next() % max
+    }
+
+    fn pick<'a>(&mut self, actors: &'a [Address]) -> &'a Address {
+        &actors[self.below(actors.len() as u64) as usize]
+    }
+}
+
+// los carros siempre son de un owner, pero cualquier actor (incluido el admin) puede llamar
+struct Actors {
+    owners: [Address; OWNERS],
+    all: [Address; ACTORS],
+}
+
+impl Actors {
+    fn setup(test: &ContractTest, rng: &mut Rng) -> Self {
+        let ContractTest { env, contract, admin, token, .. } = test;
+
+        let owners: [Address; OWNERS] = core::array::from_fn(|_| Address::generate(env));
+        let renters: [Address; RENTERS] = core::array::from_fn(|_| Address::generate(env));
+
+        let all: [Address; ACTORS] = core::array::from_fn(|i| match i {
+            i if i < OWNERS => owners[i].clone(),
+            i if i < OWNERS + RENTERS => renters[i - OWNERS].clone(),
+            _ => admin.clone(),
+        });
+
+        // deposito, prima y referidos activos para que todos los ledgers se muevan
+        contract.set_category_rules(admin, &CarCategory::Economy, &0_u32, &0_u32, &DEPOSIT);
+        contract.set_insurance_config(admin, &300_u32, &1_000_000_i128, &604_800_u64);
+        contract.set_referral_share(admin, &2_500_u32);
+
+        for actor in all.iter() {
+            token.1.mint(actor, &ACTOR_FUNDS);
+            let _ = contract.try_register_referrer(actor, rng.pick(&all));
+        }
+
+        for owner in owners.iter() {
+            contract.add_car(admin, owner, &(1 + rng.below(5_000) as i128), &CarCategory::Economy, &None);
+            contract.set_driver_fee(owner, &DRIVER_FEE_PER_DAY);
+        }
+
+        Actors { owners, all }
+    }
+}
+
+// ejecuta una llamada al azar, los errores del contrato son validos y se ignoran
+fn random_step(test: &ContractTest, rng: &mut Rng, actors: &Actors) {
+    let ContractTest { contract, .. } = test;
+    let Actors { owners, all } = actors;
+
+    match rng.below(10) {
+        0 => {
+            let price = 1 + rng.below(5_000) as i128;
+            let category = if rng.below(2) == 0 { CarCategory::Economy } else { CarCategory::Suv };
+            let _ = contract.try_add_car(rng.pick(all), rng.pick(owners), &price, &category, &None);
+        }
+        1 => {
+            let days = 1 + rng.below(10) as u32;
+            let _ = contract.try_rental(rng.pick(all), rng.pick(owners), &days, &ACTOR_FUNDS, &None, &0, &0);
+        }
+        2 => {
+            let _ = contract.try_complete_rental(rng.pick(owners), rng.pick(all));
+        }
+        3 => {
+            let caller = rng.pick(all);
+            let available = contract.try_get_available_withdraw_payowner(caller);
+            let available = available.ok().and_then(|value| value.ok()).unwrap_or(0);
+            let amount = rng.below(available as u64 + 10) as i128;
+            let _ = contract.try_payout_owner(caller, &amount);
+        }
+        4 => {
+            let commission = contract.get_admin_commission();
+            let amount = rng.below(commission as u64 + 10) as i128;
+            let _ = contract.try_payout_admin(rng.pick(all), &amount);
+        }
+        5 => {
+            let _ = contract.try_withdraw_all(rng.pick(all));
+        }
+        6 => {
+            let _ = contract.try_add_rental_driver(rng.pick(all), rng.pick(owners), rng.pick(all));
+        }
+        7 => {
+            let _ = contract.try_accept_rental_driver(rng.pick(all), rng.pick(all), rng.pick(owners));
+        }
+        8 => {
+            let _ = contract.try_claim_referral_rewards(rng.pick(all));
+        }
+        _ => {
+            let _ = contract.try_remove_car(rng.pick(all), rng.pick(owners));
+        }
+    }
+}
+
+fn assert_invariants(test: &ContractTest, actors: &Actors, step: u32) {
+    let ContractTest { env, contract, token, .. } = test;
+    let Actors { owners, all } = actors;
+
+    let (owed, owner_credits, commission) = env.as_contract(&contract.address, || {
+        let contract_balance = read_contract_balance(env);
+        let commission = read_commission(env);
+        let insurance_pool = read_insurance_pool(env);
+
+        assert!(contract_balance >= 0, "step {step}: negative contract balance");
+        assert!(commission >= 0, "step {step}: negative commission");
+        assert!(insurance_pool >= 0, "step {step}: negative insurance pool");
+
+        let referral_balances: i128 = all.iter().map(|actor| read_referral_balance(env, actor)).sum();
+        let mut owner_credits: i128 = all.iter().map(|actor| read_holder_credit(env, actor)).sum();
+        let mut held = 0_i128;
+
+        for owner in owners.iter() {
+            let mut rentals = 0;
+
+            // depositos y cargos de conductores que todavia no se liberaron siguen retenidos en el contrato
+            for renter in all.iter() {
+                if let Ok(rental) = read_rental(env, renter, owner) {
+                    rentals += 1;
+                    held += rental.deposit;
+                    held += rental.drivers.iter()
+                        .filter(|d| !d.accepted || rental.started_at.is_none())
+                        .map(|d| d.fee)
+                        .sum::<i128>();
+                }
+            }
+
+            let Ok(car) = read_car(env, owner) else {
+                assert_eq!(rentals, 0, "step {step}: rental left behind by a removed car");
+                continue;
+            };
+
+            assert!(car.available_to_withdraw >= 0, "step {step}: negative owner credit");
+            owner_credits += car.available_to_withdraw + car.pending_to_withdraw;
+            owner_credits += read_held_holder_credit(env, owner).values().iter().sum::<i128>();
+
+            // un carro esta rentado si y solo si tiene exactamente una renta activa
+            let expected = if car.car_status == CarStatus::Rented { 1 } else { 0 };
+
+            assert_eq!(rentals, expected, "step {step}: car status and rentals disagree");
+        }
+
+        // lo acreditado a los owners sale del ContractBalance, nunca de la comision
+        assert!(owner_credits <= contract_balance, "step {step}: owner credits exceed contract balance");
+
+        (contract_balance + commission + insurance_pool + referral_balances + held, owner_credits, commission)
+    });
+
+    let balance = token.0.balance(&contract.address);
+
+    assert!(
+        balance >= owner_credits + commission,
+        "step {step}: contract cannot cover owner credits and commission"
+    );
+
+    // cada token del contrato tiene que estar asignado a exactamente un ledger
+    assert_eq!(
+        balance,
+        owed,
+        "step {step}: contract balance does not match its ledgers"
+    );
+}
+
+#[test]
+pub fn test_random_call_sequences_keep_invariants() {
+    for seed in 0..SEEDS {
+        let test = ContractTest::setup();
+        test.env.mock_all_auths();
+        test.env.cost_estimate().budget().reset_unlimited();
+
+        let mut rng = Rng::new(seed);
+        let actors = Actors::setup(&test, &mut rng);
+
+        assert_invariants(&test, &actors, 0);
+
+        for step in 1..=STEPS {
+            random_step(&test, &mut rng, &actors);
+            assert_invariants(&test, &actors, step);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    storage::{rental::{has_rental, read_rental}, types::car_status::CarStatus},
+    tests::config::contract::ContractTest,
+};
+
+#[test]
+pub fn test_complete_rental_mints_loyalty_points() {
+    let ContractTest { env, contract, admin, token, loyalty_token } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4500_i128;
+
+    token.1.mint(&renter, &amount);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_loyalty_config(&admin, &loyalty_token.0.address, &1_000_u32, &10_000_u32);
+
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+    assert_eq!(loyalty_token.0.balance(&renter), 0);
+
+    contract.complete_rental(&owner, &renter);
+
+    // 10% de lo pagado en puntos
+    assert_eq!(loyalty_token.0.balance(&renter), 450);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+    assert!(!env.as_contract(&contract.address, || has_rental(&env, &renter, &owner)));
+}
+
+#[test]
+pub fn test_rental_burns_loyalty_points_for_discount() {
+    let ContractTest { env, contract, admin, token, loyalty_token } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4500_i128;
+
+    token.1.mint(&renter, &(amount * 2));
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_loyalty_config(&admin, &loyalty_token.0.address, &1_000_u32, &10_000_u32);
+
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+    contract.complete_rental(&owner, &renter);
+    let commission = contract.get_admin_commission();
+
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &100, &0);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner).unwrap());
+    assert_eq!(rental.amount, amount - 100);
+    assert_eq!(rental.loyalty_discount, 100);
+    assert_eq!(loyalty_token.0.balance(&renter), 350);
+    assert_eq!(token.0.balance(&renter), 100);
+
+    // el owner recibe lo mismo, el descuento sale de la comision
+    assert_eq!(contract.get_available_withdraw_payowner(&owner), (amount - (amount/100)*2) * 2);
+    assert_eq!(contract.get_admin_commission(), commission + (amount/100)*2 - 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #39)")]
+pub fn test_burn_points_without_loyalty_program_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token.1.mint(&renter, &4500_i128);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+
+    contract.rental(&renter, &owner, &3_u32, &4500_i128, &None, &100, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #41)")]
+pub fn test_burn_points_above_price_fails() {
+    let ContractTest { env, contract, admin, token, loyalty_token } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token.1.mint(&renter, &4500_i128);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_loyalty_config(&admin, &loyalty_token.0.address, &1_000_u32, &10_000_u32);
+
+    contract.rental(&renter, &owner, &3_u32, &4500_i128, &None, &4501, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+pub fn test_complete_rental_not_found_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.complete_rental(&owner, &Address::generate(&env));
+}
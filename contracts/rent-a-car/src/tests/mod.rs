@@ -8,4 +8,27 @@ pub mod rental;
 pub mod remove_car;
 pub mod payout_owner;
 pub mod payout_admin;
-pub mod auth;
\ No newline at end of file
+pub mod auth;
+pub mod roles;
+pub mod withdrawal;
+pub mod payout_address;
+pub mod withdraw_all;
+pub mod pricing;
+pub mod discount;
+pub mod coupon;
+pub mod referral;
+pub mod loyalty;
+pub mod nft;
+pub mod co_owner;
+pub mod insurance;
+pub mod storage_errors;
+pub mod invariants;
+pub mod budget;
+pub mod zone;
+pub mod category;
+pub mod usage;
+pub mod condition;
+pub mod device;
+pub mod pickup;
+pub mod driver;
+pub mod rental_limits;
\ No newline at end of file
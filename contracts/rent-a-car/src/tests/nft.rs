@@ -0,0 +1,167 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::storage::types::car_category::CarCategory;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+pub fn test_add_car_mints_nft_to_owner() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let second_owner = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.add_car(&admin, &second_owner, &1500_i128, &CarCategory::Economy, &None);
+
+    let token_id = contract.get_car_token_id(&owner);
+
+    assert_eq!(contract.owner_of(&token_id), owner);
+    assert_eq!(contract.get_car_holder(&owner), owner);
+    assert_ne!(contract.get_car_token_id(&second_owner), token_id);
+}
+
+#[test]
+pub fn test_transfer_moves_payout_rights_to_new_holder() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4500_i128;
+    token.1.mint(&renter, &amount);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_payout_address(&owner, &owner);
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+    contract.complete_rental(&owner, &renter);
+
+    let token_id = contract.get_car_token_id(&owner);
+    contract.transfer(&owner, &buyer, &token_id);
+
+    assert_eq!(contract.get_car_holder(&owner), buyer);
+    assert_eq!(contract.get_payout_address(&owner), buyer);
+
+    // lo ganado antes de la venta sigue siendo del vendedor
+    assert_eq!(contract.get_available_withdraw_payowner(&owner), 0);
+    let net = amount - (amount/100)*2;
+    assert_eq!(contract.get_holder_credit(&owner), net);
+
+    contract.withdraw_holder_credit(&owner, &net);
+
+    assert_eq!(token.0.balance(&owner), net);
+    assert_eq!(token.0.balance(&buyer), 0);
+    assert_eq!(contract.get_holder_credit(&owner), 0);
+}
+
+#[test]
+pub fn test_credit_of_car_sold_mid_rental_is_held_until_completion() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4500_i128;
+    let net = amount - (amount/100)*2;
+    token.1.mint(&renter, &amount);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+    contract.transfer(&owner, &buyer, &contract.get_car_token_id(&owner));
+
+    // la renta sigue en curso, el vendedor todavia no puede retirar lo que gano con ella
+    assert_eq!(contract.get_holder_credit(&owner), 0);
+    assert!(contract.try_withdraw_holder_credit(&owner, &net).is_err());
+    assert_eq!(contract.get_available_withdraw_payowner(&owner), 0);
+
+    contract.complete_rental(&owner, &renter);
+
+    assert_eq!(contract.get_holder_credit(&owner), net);
+    contract.withdraw_holder_credit(&owner, &net);
+    assert_eq!(token.0.balance(&owner), net);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+pub fn test_buyer_cannot_withdraw_revenue_earned_before_transfer() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4500_i128;
+    token.1.mint(&renter, &amount);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+    contract.complete_rental(&owner, &renter);
+
+    contract.transfer(&owner, &buyer, &contract.get_car_token_id(&owner));
+    contract.payout_owner(&owner, &1_i128);
+}
+
+#[test]
+pub fn test_holder_can_update_price() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.transfer(&owner, &buyer, &contract.get_car_token_id(&owner));
+    contract.update_price(&owner, &2000_i128);
+
+    assert_eq!(contract.quote_rental(&owner, &1_u32, &0), 2000_i128);
+}
+
+#[test]
+pub fn test_holder_can_delist_car() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    let token_id = contract.get_car_token_id(&owner);
+    contract.remove_car(&owner, &owner);
+
+    assert_eq!(contract.owner_of(&token_id), owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+pub fn test_holder_cannot_rent_own_car() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    token.1.mint(&buyer, &4500_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.transfer(&owner, &buyer, &contract.get_car_token_id(&owner));
+    contract.rental(&buyer, &owner, &3_u32, &4500_i128, &None, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+pub fn test_update_price_with_zero_price_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.update_price(&owner, &0_i128);
+}
@@ -0,0 +1,88 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    events::payout_address::PayoutAddressChangedEvent,
+    storage::{car::{read_car, write_car}, types::car_status::CarStatus},
+    tests::config::contract::ContractTest,
+};
+use crate::tests::config::utils::{get_contract_events, get_event_payload};
+
+#[test]
+pub fn test_payout_address_defaults_to_owner() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+
+    assert_eq!(contract.get_payout_address(&owner), owner);
+}
+
+#[test]
+pub fn test_set_payout_address_successfully() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let company_treasury = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_payout_address(&owner, &company_treasury);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_payout_address(&owner), company_treasury);
+    assert_eq!(contract_events.len(), 1);
+    assert_eq!(
+        get_event_payload::<PayoutAddressChangedEvent>(&env, &contract_events, "payout_address_changed"),
+        PayoutAddressChangedEvent {
+            owner: owner.clone(),
+            car_id: contract.get_car_token_id(&owner),
+            payout_address: company_treasury,
+        }
+    );
+}
+
+#[test]
+pub fn test_payout_owner_sends_to_payout_address() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let company_treasury = Address::generate(&env);
+    let amount = 4500_i128;
+
+    env.mock_all_auths();
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_payout_address(&owner, &company_treasury);
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+
+    env.as_contract(&contract.address, || {
+        let mut car = read_car(&env, &owner).unwrap();
+        car.car_status = CarStatus::Available;
+        write_car(&env, &owner, &car);
+    });
+
+    let net = amount - (amount/100)*2;
+    contract.payout_owner(&owner, &net);
+
+    assert_eq!(token_client.balance(&company_treasury), net);
+    assert_eq!(token_client.balance(&owner), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+pub fn test_set_payout_address_car_not_found_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.set_payout_address(&owner, &Address::generate(&env));
+}
@@ -1,18 +1,19 @@
-use soroban_sdk::{testutils::Address as _, Address, IntoVal, Symbol, vec};
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::storage::types::car_category::CarCategory;
 use crate::{
+    events::{balance::AdminCommissionChangedEvent, payout_admin::CommissionPayoutEvent},
     storage::{admin::read_commission},
     tests::config::contract::ContractTest,
 };
-use crate::tests::config::utils::get_contract_events;
+use crate::tests::config::utils::{get_contract_events, get_event_payload};
 
 #[test]
 pub fn test_payout_admin_successfully() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
 
-    let admin = Address::generate(&env);
     let amount = 4500_i128;
-    let price_per_day = 300_i128;
-    let owner = admin.clone();
+    let price_per_day = 1125_i128;
+    let owner = Address::generate(&env);
     let renter = Address::generate(&env);
     let total_days = 4_u32;
 
@@ -22,8 +23,8 @@ pub fn test_payout_admin_successfully() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&admin, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.add_car(&admin, &owner, &price_per_day, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &total_days, &amount, &None, &0, &0);
 
     let commission = env.as_contract(&contract.address, || read_commission(&env));
     assert_eq!(commission, (amount/100)*2);
@@ -33,19 +34,84 @@ pub fn test_payout_admin_successfully() {
 
     let contract_balance = env.as_contract(&contract.address, || read_commission(&env));
     assert_eq!(contract_balance, 0);
-        assert_eq!(
-        contract_events,
-        vec![
-            &env,
-            (
-                contract.address.clone(),
-                vec![
-                    &env,
-                    *Symbol::new(&env, "payout_commissions").as_val(),
-                    admin.clone().into_val(&env),
-                ],
-                commission.into_val(&env)
-            )
-        ]
+    assert_eq!(
+        get_event_payload::<CommissionPayoutEvent>(&env, &contract_events, "payout_commissions"),
+        CommissionPayoutEvent { to: admin.clone(), amount: commission }
     );
-}
\ No newline at end of file
+    assert_eq!(
+        get_event_payload::<AdminCommissionChangedEvent>(&env, &contract_events, "admin_commission"),
+        AdminCommissionChangedEvent { previous: commission, current: 0 }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_payout_admin_by_non_admin_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let attacker = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1125_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &4_u32, &4500_i128, &None, &0, &0);
+
+    let commission = contract.get_admin_commission();
+    contract.payout_admin(&attacker, &commission);
+}
+
+#[test]
+pub fn test_payout_admin_sends_commission_to_treasury() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let treasury = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1125_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &4_u32, &4500_i128, &None, &0, &0);
+    contract.set_treasury(&admin, &treasury);
+    assert_eq!(contract.get_treasury(), treasury);
+
+    let commission = contract.get_admin_commission();
+    contract.payout_admin(&admin, &commission);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(token_client.balance(&treasury), commission);
+    assert_eq!(token_client.balance(&admin), 0);
+    assert_eq!(
+        get_event_payload::<CommissionPayoutEvent>(&env, &contract_events, "payout_commissions"),
+        CommissionPayoutEvent { to: treasury.clone(), amount: commission }
+    );
+}
+
+#[test]
+pub fn test_treasury_can_payout_admin() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let treasury = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1125_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &4_u32, &4500_i128, &None, &0, &0);
+    contract.set_treasury(&admin, &treasury);
+
+    let commission = contract.get_admin_commission();
+    contract.payout_admin(&treasury, &commission);
+
+    assert_eq!(token_client.balance(&treasury), commission);
+    assert_eq!(contract.get_admin_commission(), 0);
+}
@@ -1,14 +1,16 @@
-use soroban_sdk::{testutils::Address as _, Address, IntoVal, Symbol, vec};
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::storage::types::car_category::CarCategory;
 use crate::{
+    events::{balance::ContractBalanceChangedEvent, payout_owner::OwnerPayoutEvent},
     storage::{car::{read_car, write_car}, contract_balance::read_contract_balance},
     storage::types::car_status::CarStatus,
     tests::config::contract::ContractTest,
 };
-use crate::tests::config::utils::get_contract_events;
+use crate::tests::config::utils::{get_contract_events, get_event_payload};
 
 #[test]
 pub fn test_payout_owner_successfully() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -22,8 +24,8 @@ pub fn test_payout_owner_successfully() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.add_car(&admin, &owner, &price_per_day, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &total_days, &amount, &None, &0, &0);
 
     let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
     let commission = (amount/100)*2;
@@ -32,7 +34,7 @@ pub fn test_payout_owner_successfully() {
 
     // cambiar el estado del carro a available
     env.as_contract(&contract.address, || {
-        let mut car = read_car(&env, &owner);
+        let mut car = read_car(&env, &owner).unwrap();
         car.car_status = CarStatus::Available;
         write_car(&env, &owner, &car);
     });
@@ -40,24 +42,43 @@ pub fn test_payout_owner_successfully() {
     contract.payout_owner(&owner, &net);
     let contract_events = get_contract_events(&env, &contract.address);
 
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner));
-    assert_eq!(car.available_to_withdraw, commission);
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
+    assert_eq!(car.available_to_withdraw, 0);
 
     let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
     assert_eq!(contract_balance, 0);
-        assert_eq!(
-        contract_events,
-        vec![
-            &env,
-            (
-                contract.address.clone(),
-                vec![
-                    &env,
-                    *Symbol::new(&env, "payout").as_val(),
-                    owner.clone().into_val(&env),
-                ],
-                net.into_val(&env)
-            )
-        ]
+    assert_eq!(
+        get_event_payload::<OwnerPayoutEvent>(&env, &contract_events, "payout"),
+        OwnerPayoutEvent { owner: owner.clone(), car_id: car.token_id, to: owner.clone(), amount: net }
     );
-}
\ No newline at end of file
+    assert_eq!(
+        get_event_payload::<ContractBalanceChangedEvent>(&env, &contract_events, "contract_balance"),
+        ContractBalanceChangedEvent { previous: net, current: 0 }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+pub fn test_payout_owner_cannot_withdraw_commission() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4500_i128;
+
+    env.mock_all_auths();
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+
+    env.as_contract(&contract.address, || {
+        let mut car = read_car(&env, &owner).unwrap();
+        car.car_status = CarStatus::Available;
+        write_car(&env, &owner, &car);
+    });
+
+    // el owner solo tiene acreditado el monto neto, no el total que pago el renter
+    contract.payout_owner(&owner, &amount);
+}
@@ -0,0 +1,195 @@
+use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    events::pickup::PickupConfirmedEvent,
+    storage::{
+        admin::read_commission, car::read_car, contract_balance::read_contract_balance, rental::read_rental,
+        types::coupon_discount::CouponDiscount,
+    },
+    tests::config::{contract::ContractTest, utils::{get_contract_events, get_event_payload}},
+};
+
+fn pickup_code(env: &Env) -> (Bytes, BytesN<32>) {
+    let code = Bytes::from_slice(env, b"4821-KEYBOX");
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+
+    (code, code_hash)
+}
+
+#[test]
+pub fn test_confirm_pickup_releases_pending_credit() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (code, code_hash) = pickup_code(&env);
+    let credit = 3000 - (3000 / 100) * 2;
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_pickup_code_required(&owner, &true);
+    contract.rental(&renter, &owner, &2_u32, &3000_i128, &None, &0, &0);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
+    assert_eq!(car.pending_to_withdraw, credit);
+    assert_eq!(car.available_to_withdraw, 0);
+
+    contract.commit_pickup_code(&owner, &renter, &code_hash);
+    contract.confirm_pickup(&renter, &owner, &code);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        get_event_payload::<PickupConfirmedEvent>(&env, &contract_events, "pickup_confirmed"),
+        PickupConfirmedEvent {
+            renter: renter.clone(),
+            owner: owner.clone(),
+            rental_id: 0,
+            started_at: env.ledger().timestamp(),
+            released_credit: credit,
+        }
+    );
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
+    assert_eq!(car.pending_to_withdraw, 0);
+    assert_eq!(car.available_to_withdraw, credit);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner).unwrap());
+    assert_eq!(rental.started_at, Some(env.ledger().timestamp()));
+}
+
+#[test]
+pub fn test_no_show_is_refunded_on_completion() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, code_hash) = pickup_code(&env);
+    let commission = (3000 / 100) * 2;
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_pickup_code_required(&owner, &true);
+    contract.rental(&renter, &owner, &2_u32, &3000_i128, &None, &0, &0);
+    contract.commit_pickup_code(&owner, &renter, &code_hash);
+
+    contract.complete_rental(&owner, &renter);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - commission);
+    assert_eq!(env.as_contract(&contract.address, || read_contract_balance(&env)), 0);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
+    assert_eq!(car.pending_to_withdraw, 0);
+    assert_eq!(car.available_to_withdraw, 0);
+}
+
+#[test]
+pub fn test_no_show_with_coupon_refunds_only_what_the_renter_paid() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, code_hash) = pickup_code(&env);
+
+    // una renta previa de 50_000 deja 1_000 de comision para subsidiar el cupon
+    let other_owner = Address::generate(&env);
+    let other_renter = Address::generate(&env);
+    token_admin.mint(&other_renter, &50_000_i128);
+    contract.add_car(&admin, &other_owner, &10_000_i128, &CarCategory::Economy, &None);
+    contract.rental(&other_renter, &other_owner, &5_u32, &50_000_i128, &None, &0, &0);
+
+    let promo = Bytes::from_slice(&env, b"SUMMER25");
+    let promo_hash: BytesN<32> = env.crypto().sha256(&promo).into();
+    let expires_at = env.ledger().timestamp() + 3600;
+    contract.create_coupon(&admin, &promo_hash, &CouponDiscount::Bps(1_000), &10_u32, &1_u32, &expires_at);
+
+    token_admin.mint(&renter, &3_000_i128);
+
+    contract.add_car(&admin, &owner, &1_000_i128, &CarCategory::Economy, &None);
+    contract.set_pickup_code_required(&owner, &true);
+    contract.rental(&renter, &owner, &3_u32, &3_000_i128, &Some(promo), &0, &0);
+    contract.commit_pickup_code(&owner, &renter, &code_hash);
+
+    // paga 3_000 - 300 de cupon, la comision de 60 absorbio los 300
+    assert_eq!(token_client.balance(&renter), 300);
+    assert_eq!(env.as_contract(&contract.address, || read_commission(&env)), 1_000 + 60 - 300);
+
+    contract.complete_rental(&owner, &renter);
+
+    // se le devuelve lo pagado menos la comision, y el subsidio vuelve a la comision
+    assert_eq!(token_client.balance(&renter), 3_000 - 60);
+    assert_eq!(env.as_contract(&contract.address, || read_commission(&env)), 1_000 + 60);
+    assert_eq!(env.as_contract(&contract.address, || read_contract_balance(&env)), 50_000 - 1_000);
+    assert_eq!(token_client.balance(&contract.address), 50_000 + 60);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
+    assert_eq!(car.pending_to_withdraw, 0);
+    assert_eq!(car.available_to_withdraw, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #70)")]
+pub fn test_confirm_pickup_with_wrong_code_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, code_hash) = pickup_code(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+    contract.commit_pickup_code(&owner, &renter, &code_hash);
+
+    contract.confirm_pickup(&renter, &owner, &Bytes::from_slice(&env, b"0000-KEYBOX"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #69)")]
+pub fn test_confirm_pickup_without_commit_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (code, _) = pickup_code(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.confirm_pickup(&renter, &owner, &code);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #71)")]
+pub fn test_confirm_pickup_twice_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (code, code_hash) = pickup_code(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+    contract.commit_pickup_code(&owner, &renter, &code_hash);
+
+    contract.confirm_pickup(&renter, &owner, &code);
+    contract.confirm_pickup(&renter, &owner, &code);
+}
\ No newline at end of file
@@ -0,0 +1,122 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, Vec};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    storage::{rental::read_rental, structs::pricing::SeasonalRate},
+    tests::config::contract::ContractTest,
+};
+
+const MONDAY_2024_01_01: u64 = 1_704_067_200;
+const FRIDAY_2024_01_05: u64 = MONDAY_2024_01_01 + 4 * 86_400;
+
+#[test]
+pub fn test_rental_applies_weekend_multiplier() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(FRIDAY_2024_01_05);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let weekend = vec![&env, 10_000_u32, 10_000, 10_000, 10_000, 10_000, 15_000, 15_000];
+
+    token.1.mint(&renter, &5_000_i128);
+    contract.add_car(&admin, &owner, &1_000_i128, &CarCategory::Economy, &None);
+    contract.set_pricing_rules(&owner, &weekend, &Vec::new(&env));
+
+    // viernes 1_000 + sabado 1_500 + domingo 1_500
+    assert_eq!(contract.quote_rental(&owner, &3_u32, &0), 4_000);
+
+    contract.rental(&renter, &owner, &3_u32, &5_000_i128, &None, &0, &0);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner).unwrap());
+    assert_eq!(rental.amount, 4_000);
+    assert_eq!(token.0.balance(&renter), 1_000);
+}
+
+#[test]
+pub fn test_rental_applies_seasonal_rate() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(MONDAY_2024_01_01);
+
+    let owner = Address::generate(&env);
+    let holiday = SeasonalRate {
+        start: MONDAY_2024_01_01 + 86_400,
+        end: MONDAY_2024_01_01 + 2 * 86_400,
+        multiplier_bps: 20_000,
+    };
+
+    contract.add_car(&admin, &owner, &1_000_i128, &CarCategory::Economy, &None);
+    contract.set_pricing_rules(&owner, &Vec::new(&env), &vec![&env, holiday]);
+
+    let rules = contract.get_pricing_rules(&owner);
+    assert_eq!(rules.seasonal_rates.len(), 1);
+
+    // lunes 1_000 + martes feriado 2_000 + miercoles 1_000
+    assert_eq!(contract.quote_rental(&owner, &3_u32, &0), 4_000);
+}
+
+#[test]
+pub fn test_rental_without_rules_uses_flat_price() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1_500_i128, &CarCategory::Economy, &None);
+
+    let rules = contract.get_pricing_rules(&owner);
+    assert!(rules.weekday_multipliers_bps.is_empty());
+    assert_eq!(contract.quote_rental(&owner, &3_u32, &0), 4_500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+pub fn test_rental_with_amount_below_price_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token.1.mint(&renter, &10_000_i128);
+    contract.add_car(&admin, &owner, &1_500_i128, &CarCategory::Economy, &None);
+
+    contract.rental(&renter, &owner, &3_u32, &4_499_i128, &None, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+pub fn test_set_pricing_rules_with_incomplete_week_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let six_days = vec![&env, 10_000_u32, 10_000, 10_000, 10_000, 10_000, 15_000];
+
+    contract.add_car(&admin, &owner, &1_000_i128, &CarCategory::Economy, &None);
+    contract.set_pricing_rules(&owner, &six_days, &Vec::new(&env));
+}
+
+#[test]
+pub fn test_advance_booking_is_priced_from_start_at() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(MONDAY_2024_01_01);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let weekend = vec![&env, 10_000_u32, 10_000, 10_000, 10_000, 10_000, 15_000, 15_000];
+
+    token.1.mint(&renter, &5_000_i128);
+    contract.add_car(&admin, &owner, &1_000_i128, &CarCategory::Economy, &None);
+    contract.set_pricing_rules(&owner, &weekend, &Vec::new(&env));
+
+    // reservado el lunes para el viernes: se cobra viernes, sabado y domingo
+    assert_eq!(contract.quote_rental(&owner, &3_u32, &0), 3_000);
+    assert_eq!(contract.quote_rental(&owner, &3_u32, &FRIDAY_2024_01_05), 4_000);
+
+    contract.rental(&renter, &owner, &3_u32, &5_000_i128, &None, &0, &FRIDAY_2024_01_05);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner).unwrap());
+    assert_eq!(rental.amount, 4_000);
+}
@@ -0,0 +1,91 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::storage::types::car_category::CarCategory;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+pub fn test_referrer_earns_share_of_commission() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    let amount = 4500_i128;
+    let commission = (amount/100)*2;
+
+    token.1.mint(&renter, &amount);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_referral_share(&admin, &5_000_u32);
+    contract.register_referrer(&renter, &referrer);
+    assert_eq!(contract.get_referrer(&renter), referrer);
+
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+
+    assert_eq!(contract.get_referral_balance(&referrer), commission / 2);
+    assert_eq!(contract.get_admin_commission(), commission - commission / 2);
+
+    let claimed = contract.claim_referral_rewards(&referrer);
+
+    assert_eq!(claimed, commission / 2);
+    assert_eq!(token.0.balance(&referrer), commission / 2);
+    assert_eq!(contract.get_referral_balance(&referrer), 0);
+}
+
+#[test]
+pub fn test_rental_without_referrer_keeps_full_commission() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4500_i128;
+
+    token.1.mint(&renter, &amount);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_referral_share(&admin, &5_000_u32);
+
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+
+    assert_eq!(contract.get_admin_commission(), (amount/100)*2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")]
+pub fn test_register_referrer_twice_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let renter = Address::generate(&env);
+
+    contract.register_referrer(&renter, &Address::generate(&env));
+    contract.register_referrer(&renter, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #35)")]
+pub fn test_self_referral_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let renter = Address::generate(&env);
+
+    contract.register_referrer(&renter, &renter);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #38)")]
+pub fn test_claim_without_rewards_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    contract.claim_referral_rewards(&Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #37)")]
+pub fn test_referral_share_above_100_percent_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    contract.set_referral_share(&admin, &10_001_u32);
+}
@@ -1,53 +1,63 @@
-use soroban_sdk::{testutils::Address as _, Address, vec, IntoVal, Symbol};
-use crate::{storage::{car::has_car}, tests::config::contract::ContractTest};
-use crate::tests::config::utils::get_contract_events;
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::storage::types::car_category::CarCategory;
+use crate::{events::remove_car::CarRemovedEvent, storage::{car::has_car}, tests::config::contract::ContractTest};
+use crate::tests::config::utils::{get_contract_events, get_event_payload};
 
 #[test]
 pub fn test_remove_car_deletes_from_storage() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
 
     env.mock_all_auths();
 
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
 
-    contract.add_car(&owner, &price_per_day);
+    contract.add_car(&admin, &owner, &price_per_day, &CarCategory::Economy, &None);
+    let car_id = contract.get_car_token_id(&owner);
     assert!(env.as_contract(&contract.address, || {
         has_car(&env, &owner)
     }));
 
-    contract.remove_car(&owner);
+    contract.remove_car(&admin, &owner);
     let contract_events = get_contract_events(&env, &contract.address);
 
     assert!(!env.as_contract(&contract.address, || {
         has_car(&env, &owner)
     }));
 
+    assert_eq!(contract_events.len(), 1);
     assert_eq!(
-        contract_events,
-        vec![
-            &env,
-            (
-                contract.address.clone(),
-                vec![
-                    &env,
-                    *Symbol::new(&env, "car_removed").as_val(),
-                    owner.clone().into_val(&env),
-                ],
-                ().into_val(&env)
-            )
-        ]
+        get_event_payload::<CarRemovedEvent>(&env, &contract_events, "car_removed"),
+        CarRemovedEvent { owner: owner.clone(), car_id }
     );
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #2)")]
 pub fn test_remove_car_not_found_fails() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
 
     env.mock_all_auths();
 
     let owner = Address::generate(&env);
     
-    contract.remove_car(&owner);
+    contract.remove_car(&admin, &owner);
+}
+
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+pub fn test_remove_rented_car_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    token.1.mint(&renter, &4500_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &3_u32, &4500_i128, &None, &0, &0);
+
+    contract.remove_car(&admin, &owner);
 }
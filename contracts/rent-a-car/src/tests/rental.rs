@@ -1,10 +1,12 @@
-use soroban_sdk::{testutils::Address as _, Address, vec, Symbol, IntoVal};
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::storage::types::car_category::CarCategory;
 use crate::{storage::{car::read_car, contract_balance::read_contract_balance, rental::read_rental, types::car_status::CarStatus}, tests::config::contract::ContractTest};
-use crate::tests::config::utils::get_contract_events;
+use crate::events::{balance::ContractBalanceChangedEvent, commission::CommissionAccruedEvent, rental::RentedEvent};
+use crate::tests::config::utils::{get_contract_events, get_event_payload};
 
 #[test]
 pub fn test_rental_car_successfully() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -19,38 +21,43 @@ pub fn test_rental_car_successfully() {
     let amount_mint = 10_000_i128;
     token_admin.mint( &renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    contract.add_car(&admin, &owner, &price_per_day, &CarCategory::Economy, &None);
 
     let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
     assert_eq!(initial_contract_balance, 0);
 
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &owner, &total_days, &amount, &None, &0, &0);
     let contract_events = get_contract_events(&env, &contract.address);
 
     let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
     assert_eq!(updated_contract_balance, amount - (amount/100)*2);
 
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner));
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
     assert_eq!(car.car_status, CarStatus::Rented);
-    assert_eq!(car.available_to_withdraw, amount);
+    assert_eq!(car.available_to_withdraw, amount - (amount/100)*2);
 
-    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner).unwrap());
     assert_eq!(rental.total_days_to_rent, total_days);
     assert_eq!(rental.amount, amount);
-        assert_eq!(
-        contract_events,
-        vec![
-            &env,
-            (
-                contract.address.clone(),
-                vec![
-                    &env,
-                    *Symbol::new(&env, "rented").as_val(),
-                    renter.clone().into_val(&env),
-                    owner.clone().into_val(&env),
-                ],
-                (total_days, amount).into_val(&env)
-            )
-        ]
+    assert_eq!(rental.discount, 0);
+    assert_eq!(
+        get_event_payload::<RentedEvent>(&env, &contract_events, "rented"),
+        RentedEvent {
+            renter: renter.clone(),
+            owner: owner.clone(),
+            car_id: car.token_id,
+            rental_id: rental.id,
+            total_days,
+            amount,
+            discount: 0,
+        }
+    );
+    assert_eq!(
+        get_event_payload::<CommissionAccruedEvent>(&env, &contract_events, "commission_accrued"),
+        CommissionAccruedEvent { rental_id: rental.id, commission: (amount/100)*2, admin_commission: (amount/100)*2 }
+    );
+    assert_eq!(
+        get_event_payload::<ContractBalanceChangedEvent>(&env, &contract_events, "contract_balance"),
+        ContractBalanceChangedEvent { previous: 0, current: amount - (amount/100)*2 }
     );
 }
\ No newline at end of file
@@ -0,0 +1,114 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::storage::types::{car_category::CarCategory, car_status::CarStatus};
+use crate::{storage::rental::read_rental, tests::config::contract::ContractTest};
+
+const DAY: u64 = 86_400;
+
+#[test]
+pub fn test_rental_within_limits_succeeds() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000_000);
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let start_at = 1_000_000 + DAY;
+
+    token_admin.mint(&renter, &100_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_rental_limits(&owner, &2_u32, &30_u32, &DAY);
+    contract.rental(&renter, &owner, &2_u32, &3000_i128, &None, &0, &start_at);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner).unwrap());
+    assert_eq!(rental.start_at, start_at);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #76)")]
+pub fn test_rental_below_min_days_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &100_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_rental_limits(&owner, &2_u32, &0_u32, &0_u64);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #77)")]
+pub fn test_rental_above_max_days_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &100_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_rental_limits(&owner, &0_u32, &30_u32, &0_u64);
+    contract.rental(&renter, &owner, &31_u32, &46_500_i128, &None, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #78)")]
+pub fn test_rental_without_enough_notice_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000_000);
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &100_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_rental_limits(&owner, &0_u32, &0_u32, &DAY);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &(1_000_000 + DAY - 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #79)")]
+pub fn test_max_days_below_min_days_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_rental_limits(&owner, &5_u32, &2_u32, &0_u64);
+}
+
+#[test]
+pub fn test_advance_booking_blocks_car_until_completed() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000_000);
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let other_renter = Address::generate(&env);
+
+    token.1.mint(&renter, &100_000_i128);
+    token.1.mint(&other_renter, &100_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &(1_000_000 + 7 * DAY));
+
+    // la reserva para dentro de una semana ya deja el carro rentado
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Rented);
+    assert!(contract.try_rental(&other_renter, &owner, &1_u32, &1500_i128, &None, &0, &0).is_err());
+
+    contract.complete_rental(&owner, &renter);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+}
\ No newline at end of file
@@ -0,0 +1,130 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    storage::{admin::read_commission, car::has_car, types::role::Role},
+    tests::config::contract::ContractTest,
+};
+
+#[test]
+pub fn test_grant_and_revoke_role_successfully() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let fleet_manager = Address::generate(&env);
+
+    assert!(!contract.has_role(&fleet_manager, &Role::FleetManager));
+
+    contract.grant_role(&admin, &fleet_manager, &Role::FleetManager);
+    assert!(contract.has_role(&fleet_manager, &Role::FleetManager));
+    assert!(!contract.has_role(&fleet_manager, &Role::Treasurer));
+
+    contract.revoke_role(&admin, &fleet_manager, &Role::FleetManager);
+    assert!(!contract.has_role(&fleet_manager, &Role::FleetManager));
+}
+
+#[test]
+pub fn test_fleet_manager_can_add_and_remove_car() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let fleet_manager = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+
+    contract.grant_role(&admin, &fleet_manager, &Role::FleetManager);
+
+    contract.add_car(&fleet_manager, &owner, &price_per_day, &CarCategory::Economy, &None);
+    assert!(env.as_contract(&contract.address, || has_car(&env, &owner)));
+
+    contract.remove_car(&fleet_manager, &owner);
+    assert!(!env.as_contract(&contract.address, || has_car(&env, &owner)));
+}
+
+#[test]
+pub fn test_treasurer_can_payout_admin() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let treasurer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.grant_role(&admin, &treasurer, &Role::Treasurer);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &3_u32, &4500_i128, &None, &0, &0);
+
+    let commission = contract.get_admin_commission();
+    contract.payout_admin(&treasurer, &commission);
+
+    assert_eq!(token_client.balance(&treasurer), commission);
+    assert_eq!(env.as_contract(&contract.address, || read_commission(&env)), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_fleet_manager_cannot_payout_admin() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let fleet_manager = Address::generate(&env);
+
+    contract.grant_role(&admin, &fleet_manager, &Role::FleetManager);
+    contract.payout_admin(&fleet_manager, &100_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_revoked_fleet_manager_cannot_add_car() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let fleet_manager = Address::generate(&env);
+    let owner = Address::generate(&env);
+
+    contract.grant_role(&admin, &fleet_manager, &Role::FleetManager);
+    contract.revoke_role(&admin, &fleet_manager, &Role::FleetManager);
+    contract.add_car(&fleet_manager, &owner, &1500_i128, &CarCategory::Economy, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+pub fn test_revoke_role_not_granted_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let account = Address::generate(&env);
+
+    contract.revoke_role(&admin, &account, &Role::Arbiter);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #85)")]
+pub fn test_admin_role_cannot_be_granted() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.grant_role(&admin, &Address::generate(&env), &Role::Admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_role_holder_cannot_grant_roles() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let fleet_manager = Address::generate(&env);
+
+    contract.grant_role(&admin, &fleet_manager, &Role::FleetManager);
+    contract.grant_role(&fleet_manager, &Address::generate(&env), &Role::Treasurer);
+}
@@ -0,0 +1,117 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::storage::types::car_category::CarCategory;
+use crate::{storage::types::storage::DataKey, tests::config::contract::ContractTest};
+
+// simula un contrato migrado a medias borrando una llave de la instancia
+fn remove_instance_key(test: &ContractTest, key: &DataKey) {
+    test.env.as_contract(&test.contract.address, || {
+        test.env.storage().instance().remove(key);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+pub fn test_get_admin_without_admin_fails() {
+    let test = ContractTest::setup();
+    remove_instance_key(&test, &DataKey::Admin);
+
+    test.contract.get_admin();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+pub fn test_get_admin_commission_without_admin_fails() {
+    let test = ContractTest::setup();
+    remove_instance_key(&test, &DataKey::Admin);
+
+    test.contract.get_admin_commission();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+pub fn test_add_car_without_admin_fails() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    remove_instance_key(&test, &DataKey::Admin);
+
+    let caller = Address::generate(&test.env);
+    test.contract.add_car(&caller, &Address::generate(&test.env), &1500_i128, &CarCategory::Economy, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+pub fn test_rental_without_token_fails() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+
+    let owner = Address::generate(&test.env);
+    let renter = Address::generate(&test.env);
+
+    test.contract.add_car(&test.admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    remove_instance_key(&test, &DataKey::Token);
+
+    test.contract.rental(&renter, &owner, &3_u32, &4500_i128, &None, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+pub fn test_get_car_holder_car_not_found_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    contract.get_car_holder(&Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+pub fn test_complete_rental_without_rental_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.complete_rental(&owner, &Address::generate(&env));
+}
+
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+pub fn test_approve_missing_withdrawal_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    contract.approve_withdrawal(&Address::generate(&env), &0_u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #16)")]
+pub fn test_propose_withdrawal_without_policy_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    contract.propose_withdrawal(&admin, &100_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+pub fn test_get_treasury_not_set_fails() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    contract.get_treasury();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #46)")]
+pub fn test_get_missing_insurance_claim_fails() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    contract.get_insurance_claim(&0_u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #50)")]
+pub fn test_get_missing_zone_fails() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    contract.get_zone(&0_u32);
+}
\ No newline at end of file
@@ -0,0 +1,293 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    events::usage::{UsageChargedEvent, UsageDebtSettledEvent},
+    storage::{car::read_car, structs::usage::UsageReading, types::reading_kind::ReadingKind},
+    tests::config::{contract::ContractTest, utils::{get_contract_events, get_event_payload}},
+};
+
+#[test]
+pub fn test_readings_are_confirmed_by_owner() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &2_u32, &3000_i128, &None, &0, &0);
+
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Pickup, &12_000_u32, &80_u32);
+    assert_eq!(
+        contract.get_usage_reading(&renter, &owner, &ReadingKind::Pickup),
+        UsageReading { odometer_km: 12_000, fuel_level_pct: 80, confirmed: false }
+    );
+
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Pickup);
+    assert!(contract.get_usage_reading(&renter, &owner, &ReadingKind::Pickup).confirmed);
+}
+
+#[test]
+pub fn test_usage_charge_is_taken_from_deposit() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let deposit = 5000_i128;
+
+    token_admin.mint(&renter, &100_000_i128);
+
+    contract.add_car(&admin, &owner, &10_000_i128, &CarCategory::Suv, &None);
+    contract.set_category_rules(&admin, &CarCategory::Suv, &0_u32, &0_u32, &deposit);
+    contract.set_usage_fees(&owner, &100_u32, &10_i128, &20_i128);
+    contract.rental(&renter, &owner, &2_u32, &(20_000 + deposit), &None, &0, &0);
+
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Pickup, &1000_u32, &90_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Pickup);
+
+    // 50 km de mas y 10 puntos menos de combustible
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Return, &1250_u32, &80_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Return);
+
+    contract.complete_rental(&owner, &renter);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        get_event_payload::<UsageChargedEvent>(&env, &contract_events, "usage_charged"),
+        UsageChargedEvent {
+            renter: renter.clone(),
+            rental_id: 0,
+            extra_km: 50,
+            km_charge: 500,
+            fuel_charge: 200,
+            from_deposit: 700,
+            owed: 0,
+        }
+    );
+
+    assert_eq!(token_client.balance(&renter), 100_000 - 20_000 - 700);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
+    assert_eq!(car.available_to_withdraw, 20_000 - (20_000 / 100) * 2 + 700);
+}
+
+#[test]
+pub fn test_usage_charge_above_deposit_is_owed_by_renter() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_usage_fees(&owner, &0_u32, &5_i128, &0_i128);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Pickup, &0_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Pickup);
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Return, &200_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Return);
+
+    contract.complete_rental(&owner, &renter);
+
+    // sin deposito nada se cobra en la devolucion, el cargo queda pendiente
+    assert_eq!(token_client.balance(&renter), 10_000 - 1500);
+    assert_eq!(contract.get_usage_debt(&renter, &owner), 1000);
+    assert_eq!(contract.get_available_withdraw_payowner(&owner), 1500 - 30);
+
+    assert_eq!(contract.settle_usage_debt(&renter, &owner), 1000);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        get_event_payload::<UsageDebtSettledEvent>(&env, &contract_events, "usage_debt_settled"),
+        UsageDebtSettledEvent { renter: renter.clone(), owner: owner.clone(), amount: 1000 }
+    );
+
+    assert_eq!(token_client.balance(&renter), 10_000 - 1500 - 1000);
+    assert_eq!(token_client.balance(&contract.address), 1500 + 1000);
+    assert_eq!(contract.get_usage_debt(&renter, &owner), 0);
+    assert_eq!(contract.get_available_withdraw_payowner(&owner), 1500 - 30 + 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #83)")]
+pub fn test_renter_with_usage_debt_cannot_rent_same_car() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token.1.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_usage_fees(&owner, &0_u32, &5_i128, &0_i128);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Pickup, &0_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Pickup);
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Return, &200_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Return);
+    contract.complete_rental(&owner, &renter);
+
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #82)")]
+pub fn test_settle_without_usage_debt_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.settle_usage_debt(&Address::generate(&env), &owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #83)")]
+pub fn test_renter_with_usage_debt_cannot_rent_another_car() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token.1.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.add_car(&admin, &other_owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_usage_fees(&owner, &0_u32, &5_i128, &0_i128);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Pickup, &0_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Pickup);
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Return, &200_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Return);
+    contract.complete_rental(&owner, &renter);
+
+    contract.rental(&renter, &other_owner, &1_u32, &1500_i128, &None, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #84)")]
+pub fn test_complete_rental_requires_confirmed_return_reading() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_usage_fees(&owner, &0_u32, &5_i128, &0_i128);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Pickup, &0_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Pickup);
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Return, &200_u32, &100_u32);
+
+    contract.complete_rental(&owner, &renter);
+}
+
+#[test]
+pub fn test_owner_records_return_reading_when_renter_does_not() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_usage_fees(&owner, &0_u32, &5_i128, &0_i128);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Pickup, &0_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Pickup);
+
+    // el renter nunca envia la devolucion, el owner la registra y queda confirmada
+    contract.record_return_reading(&owner, &renter, &200_u32, &100_u32);
+    assert_eq!(
+        contract.get_usage_reading(&renter, &owner, &ReadingKind::Return),
+        UsageReading { odometer_km: 200, fuel_level_pct: 100, confirmed: true }
+    );
+
+    contract.complete_rental(&owner, &renter);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - 1500);
+    assert_eq!(contract.get_usage_debt(&renter, &owner), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #58)")]
+pub fn test_return_odometer_below_pickup_fails() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Pickup, &500_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Pickup);
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Return, &400_u32, &100_u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #59)")]
+pub fn test_return_reading_requires_confirmed_pickup() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Pickup, &500_u32, &100_u32);
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Return, &600_u32, &100_u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #60)")]
+pub fn test_confirmed_reading_cannot_be_changed() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &1_u32, &1500_i128, &None, &0, &0);
+
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Pickup, &500_u32, &100_u32);
+    contract.confirm_usage_reading(&owner, &renter, &ReadingKind::Pickup);
+    contract.submit_usage_reading(&renter, &owner, &ReadingKind::Pickup, &100_u32, &100_u32);
+}
\ No newline at end of file
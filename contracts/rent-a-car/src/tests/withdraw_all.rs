@@ -0,0 +1,163 @@
+use soroban_sdk::{testutils::Address as _, Address, vec};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    events::{payout_owner::OwnerPayoutEvent, withdraw_all::{BatchPayoutEvent, WithdrewAllEvent}},
+    storage::{
+        car::{read_car, write_car},
+        contract_balance::{read_contract_balance, write_contract_balance},
+        types::car_status::CarStatus,
+    },
+    tests::config::contract::ContractTest,
+};
+use crate::tests::config::utils::{get_contract_events, get_event_payload};
+
+// renta el carro del owner y lo deja disponible otra vez, devuelve lo acreditado al owner
+fn rent_and_return(test: &ContractTest, owner: &Address, amount: i128) -> i128 {
+    let ContractTest { env, contract, admin, token, .. } = test;
+
+    let renter = Address::generate(env);
+    token.1.mint(&renter, &amount);
+
+    contract.add_car(admin, owner, &(amount / 3), &CarCategory::Economy, &None);
+    contract.rental(&renter, owner, &3_u32, &amount, &None, &0, &0);
+
+    env.as_contract(&contract.address, || {
+        let mut car = read_car(env, owner).unwrap();
+        car.car_status = CarStatus::Available;
+        write_car(env, owner, &car);
+    });
+
+    amount - (amount/100)*2
+}
+
+#[test]
+pub fn test_withdraw_all_sweeps_available_balance() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+
+    let owner = Address::generate(&test.env);
+    let net = rent_and_return(&test, &owner, 4500_i128);
+    let ContractTest { env, contract, token, .. } = test;
+
+    let total = contract.withdraw_all(&owner);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(total, net);
+    assert_eq!(token.0.balance(&owner), net);
+    assert_eq!(contract.get_available_withdraw_payowner(&owner), 0);
+    assert_eq!(env.as_contract(&contract.address, || read_contract_balance(&env)), 0);
+    let car_id = contract.get_car_token_id(&owner);
+
+    assert_eq!(contract_events.len(), 3);
+    assert_eq!(
+        get_event_payload::<OwnerPayoutEvent>(&env, &contract_events, "payout"),
+        OwnerPayoutEvent { owner: owner.clone(), car_id, to: owner.clone(), amount: net }
+    );
+    assert_eq!(
+        get_event_payload::<WithdrewAllEvent>(&env, &contract_events, "withdraw_all"),
+        WithdrewAllEvent { holder: owner.clone(), cars: 1, total: net }
+    );
+}
+
+#[test]
+pub fn test_withdraw_all_sweeps_every_car_the_holder_holds() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+
+    let holder = Address::generate(&test.env);
+    let seller = Address::generate(&test.env);
+    let net_own = rent_and_return(&test, &holder, 4500_i128);
+    let ContractTest { env, contract, admin, token, .. } = test;
+
+    // el holder ademas le compro el NFT de su carro al seller, lo que se gane despues es del holder
+    contract.add_car(&admin, &seller, &1500_i128, &CarCategory::Economy, &None);
+    contract.transfer(&seller, &holder, &contract.get_car_token_id(&seller));
+
+    let renter = Address::generate(&env);
+    token.1.mint(&renter, &4500_i128);
+    contract.rental(&renter, &seller, &3_u32, &4500_i128, &None, &0, &0);
+    contract.complete_rental(&seller, &renter);
+
+    let net_bought = 4500 - (4500 / 100) * 2;
+
+    let total = contract.withdraw_all(&holder);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(total, net_own + net_bought);
+    assert_eq!(token.0.balance(&holder), net_own + net_bought);
+    assert_eq!(token.0.balance(&seller), 0);
+    assert_eq!(contract.get_available_withdraw_payowner(&holder), 0);
+    assert_eq!(contract.get_available_withdraw_payowner(&seller), 0);
+    assert_eq!(
+        get_event_payload::<WithdrewAllEvent>(&env, &contract_events, "withdraw_all"),
+        WithdrewAllEvent { holder: holder.clone(), cars: 2, total: net_own + net_bought }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+pub fn test_withdraw_all_does_not_pay_less_than_the_owner_credit() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+
+    let owner = Address::generate(&test.env);
+    rent_and_return(&test, &owner, 4500_i128);
+    let ContractTest { env, contract, .. } = test;
+
+    env.as_contract(&contract.address, || write_contract_balance(&env, &100_i128));
+
+    contract.withdraw_all(&owner);
+}
+
+#[test]
+pub fn test_withdraw_all_skips_rented_car() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4500_i128;
+
+    token.1.mint(&renter, &amount);
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &3_u32, &amount, &None, &0, &0);
+
+    assert_eq!(contract.withdraw_all(&owner), 0);
+    assert_eq!(token.0.balance(&owner), 0);
+}
+
+#[test]
+pub fn test_batch_payout_pays_every_owner() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+
+    let owner_1 = Address::generate(&test.env);
+    let owner_2 = Address::generate(&test.env);
+    let without_car = Address::generate(&test.env);
+    let net_1 = rent_and_return(&test, &owner_1, 4500_i128);
+    let net_2 = rent_and_return(&test, &owner_2, 9000_i128);
+    let ContractTest { env, contract, admin, token, .. } = test;
+
+    let owners = vec![&env, owner_1.clone(), owner_2.clone(), without_car];
+    let total = contract.batch_payout(&admin, &owners);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(total, net_1 + net_2);
+    assert_eq!(token.0.balance(&owner_1), net_1);
+    assert_eq!(token.0.balance(&owner_2), net_2);
+    assert_eq!(env.as_contract(&contract.address, || read_contract_balance(&env)), 0);
+    assert_eq!(contract_events.len(), 5);
+    assert_eq!(
+        get_event_payload::<BatchPayoutEvent>(&env, &contract_events, "batch_payout"),
+        BatchPayoutEvent { caller: admin.clone(), owners: 3, total: net_1 + net_2 }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+pub fn test_withdraw_all_car_not_found_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    contract.withdraw_all(&Address::generate(&env));
+}
@@ -0,0 +1,212 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address};
+use crate::storage::types::car_category::CarCategory;
+use crate::{
+    storage::{admin::read_commission, types::withdrawal_status::WithdrawalStatus},
+    tests::config::contract::ContractTest,
+};
+
+// renta un carro para que el contrato acumule comision (2% de 50_000 = 1_000)
+fn accrue_commission(test: &ContractTest) -> i128 {
+    let ContractTest { env, contract, admin, token, .. } = test;
+
+    let owner = Address::generate(env);
+    let renter = Address::generate(env);
+    let amount = 50_000_i128;
+
+    token.1.mint(&renter, &amount);
+    contract.add_car(admin, &owner, &10_000_i128, &CarCategory::Economy, &None);
+    contract.rental(&renter, &owner, &5_u32, &amount, &None, &0, &0);
+
+    contract.get_admin_commission()
+}
+
+#[test]
+pub fn test_withdrawal_executes_when_threshold_is_reached() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    let commission = accrue_commission(&test);
+    let ContractTest { env, contract, admin, token, .. } = test;
+
+    let approver_1 = Address::generate(&env);
+    let approver_2 = Address::generate(&env);
+    let approver_3 = Address::generate(&env);
+    let approvers = vec![&env, approver_1.clone(), approver_2.clone(), approver_3.clone()];
+
+    contract.set_withdrawal_policy(&admin, &approvers, &2_u32, &100_i128, &3600_u64, &86_400_u64);
+
+    let id = contract.propose_withdrawal(&admin, &commission);
+
+    contract.approve_withdrawal(&approver_1, &id);
+    let proposal = contract.get_withdrawal(&id);
+    assert_eq!(proposal.status, WithdrawalStatus::Pending);
+    assert_eq!(proposal.approvals.len(), 1);
+    assert_eq!(token.0.balance(&admin), 0);
+
+    contract.approve_withdrawal(&approver_3, &id);
+    let proposal = contract.get_withdrawal(&id);
+    assert_eq!(proposal.status, WithdrawalStatus::Executed);
+    assert_eq!(token.0.balance(&admin), commission);
+    assert_eq!(env.as_contract(&contract.address, || read_commission(&env)), 0);
+}
+
+#[test]
+pub fn test_payout_admin_below_threshold_does_not_need_approval() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    accrue_commission(&test);
+    let ContractTest { env, contract, admin, token, .. } = test;
+
+    let approvers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    contract.set_withdrawal_policy(&admin, &approvers, &2_u32, &100_i128, &3600_u64, &86_400_u64);
+
+    contract.payout_admin(&admin, &100_i128);
+    assert_eq!(token.0.balance(&admin), 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+pub fn test_payout_admin_above_threshold_requires_approval() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    accrue_commission(&test);
+    let ContractTest { env, contract, admin, .. } = test;
+
+    let approvers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    contract.set_withdrawal_policy(&admin, &approvers, &2_u32, &100_i128, &3600_u64, &86_400_u64);
+
+    contract.payout_admin(&admin, &101_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+pub fn test_split_payouts_above_threshold_require_approval() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    accrue_commission(&test);
+    let ContractTest { env, contract, admin, .. } = test;
+
+    let approvers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    contract.set_withdrawal_policy(&admin, &approvers, &2_u32, &100_i128, &3600_u64, &86_400_u64);
+
+    // cada retiro esta bajo el umbral pero juntos lo pasan
+    contract.payout_admin(&admin, &60_i128);
+    contract.payout_admin(&admin, &60_i128);
+}
+
+#[test]
+pub fn test_withdrawal_window_resets_after_period() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    accrue_commission(&test);
+    let ContractTest { env, contract, admin, token, .. } = test;
+
+    let approvers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    contract.set_withdrawal_policy(&admin, &approvers, &2_u32, &100_i128, &3600_u64, &86_400_u64);
+
+    contract.payout_admin(&admin, &60_i128);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 86_400);
+    contract.payout_admin(&admin, &60_i128);
+
+    assert_eq!(token.0.balance(&admin), 120);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")]
+pub fn test_approve_expired_withdrawal_fails() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    let commission = accrue_commission(&test);
+    let ContractTest { env, contract, admin, .. } = test;
+
+    let approver = Address::generate(&env);
+    let approvers = vec![&env, approver.clone(), Address::generate(&env)];
+    contract.set_withdrawal_policy(&admin, &approvers, &2_u32, &100_i128, &3600_u64, &86_400_u64);
+
+    let id = contract.propose_withdrawal(&admin, &commission);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+
+    contract.approve_withdrawal(&approver, &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_approve_cancelled_withdrawal_fails() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    let commission = accrue_commission(&test);
+    let ContractTest { env, contract, admin, .. } = test;
+
+    let approver = Address::generate(&env);
+    let approvers = vec![&env, approver.clone(), Address::generate(&env)];
+    contract.set_withdrawal_policy(&admin, &approvers, &2_u32, &100_i128, &3600_u64, &86_400_u64);
+
+    let id = contract.propose_withdrawal(&admin, &commission);
+    contract.cancel_withdrawal(&admin, &id);
+    assert_eq!(contract.get_withdrawal(&id).status, WithdrawalStatus::Cancelled);
+
+    contract.approve_withdrawal(&approver, &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+pub fn test_approve_withdrawal_not_an_approver_fails() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    let commission = accrue_commission(&test);
+    let ContractTest { env, contract, admin, .. } = test;
+
+    let approvers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    contract.set_withdrawal_policy(&admin, &approvers, &2_u32, &100_i128, &3600_u64, &86_400_u64);
+
+    let id = contract.propose_withdrawal(&admin, &commission);
+    contract.approve_withdrawal(&Address::generate(&env), &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+pub fn test_approve_withdrawal_twice_fails() {
+    let test = ContractTest::setup();
+    test.env.mock_all_auths();
+    let commission = accrue_commission(&test);
+    let ContractTest { env, contract, admin, .. } = test;
+
+    let approver = Address::generate(&env);
+    let approvers = vec![&env, approver.clone(), Address::generate(&env)];
+    contract.set_withdrawal_policy(&admin, &approvers, &2_u32, &100_i128, &3600_u64, &86_400_u64);
+
+    let id = contract.propose_withdrawal(&admin, &commission);
+    contract.approve_withdrawal(&approver, &id);
+    contract.approve_withdrawal(&approver, &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #16)")]
+pub fn test_set_withdrawal_policy_with_too_many_required_approvals_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let approvers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    contract.set_withdrawal_policy(&admin, &approvers, &3_u32, &100_i128, &3600_u64, &86_400_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #16)")]
+pub fn test_set_withdrawal_policy_with_duplicate_approvers_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let approver = Address::generate(&env);
+    let approvers = vec![&env, approver.clone(), approver, Address::generate(&env)];
+    contract.set_withdrawal_policy(&admin, &approvers, &2_u32, &100_i128, &3600_u64, &86_400_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #16)")]
+pub fn test_set_withdrawal_policy_with_more_required_approvals_than_distinct_approvers_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let approver = Address::generate(&env);
+    let approvers = vec![&env, approver.clone(), approver, Address::generate(&env)];
+    contract.set_withdrawal_policy(&admin, &approvers, &3_u32, &100_i128, &3600_u64, &86_400_u64);
+}
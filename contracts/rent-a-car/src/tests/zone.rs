@@ -0,0 +1,133 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, String};
+use crate::storage::types::car_category::CarCategory;
+use crate::{storage::structs::zone::CarLocation, tests::config::contract::ContractTest};
+
+#[test]
+pub fn test_assign_car_to_zone() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let pickup_point = String::from_str(&env, "Av. Central 123");
+
+    contract.set_zone(&admin, &1_u32, &String::from_str(&env, "Escazu"), &String::from_str(&env, "d1u0"));
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_car_location(&owner, &owner, &1_u32, &pickup_point);
+
+    assert_eq!(
+        contract.get_car_location(&owner),
+        CarLocation { zone_id: 1, pickup_point }
+    );
+    assert_eq!(contract.list_cars_in_zone(&1_u32, &0_u32, &10_u32), vec![&env, owner]);
+}
+
+#[test]
+pub fn test_zone_index_follows_car_moves_and_removal() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner_1 = Address::generate(&env);
+    let owner_2 = Address::generate(&env);
+    let pickup_point = String::from_str(&env, "Parqueo norte");
+
+    contract.set_zone(&admin, &1_u32, &String::from_str(&env, "Escazu"), &String::from_str(&env, "d1u0"));
+    contract.set_zone(&admin, &2_u32, &String::from_str(&env, "Heredia"), &String::from_str(&env, "d1u2"));
+    contract.add_car(&admin, &owner_1, &1500_i128, &CarCategory::Economy, &None);
+    contract.add_car(&admin, &owner_2, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_car_location(&admin, &owner_1, &1_u32, &pickup_point);
+    contract.set_car_location(&admin, &owner_2, &1_u32, &pickup_point);
+
+    contract.set_car_location(&admin, &owner_1, &2_u32, &pickup_point);
+
+    assert_eq!(contract.list_cars_in_zone(&1_u32, &0_u32, &10_u32), vec![&env, owner_2.clone()]);
+    assert_eq!(contract.list_cars_in_zone(&2_u32, &0_u32, &10_u32), vec![&env, owner_1]);
+
+    contract.remove_car(&admin, &owner_2);
+
+    assert_eq!(contract.list_cars_in_zone(&1_u32, &0_u32, &10_u32).len(), 0);
+    contract.remove_zone(&admin, &1_u32);
+}
+
+#[test]
+pub fn test_list_cars_in_zone_paginates() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let pickup_point = String::from_str(&env, "Terminal");
+    let owners = vec![&env, Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+
+    contract.set_zone(&admin, &1_u32, &String::from_str(&env, "San Jose"), &String::from_str(&env, "d1u1"));
+
+    for owner in owners.iter() {
+        contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+        contract.set_car_location(&admin, &owner, &1_u32, &pickup_point);
+    }
+
+    assert_eq!(contract.list_cars_in_zone(&1_u32, &0_u32, &2_u32), owners.slice(0..2));
+    assert_eq!(contract.list_cars_in_zone(&1_u32, &2_u32, &2_u32), owners.slice(2..3));
+    assert_eq!(contract.list_cars_in_zone(&1_u32, &5_u32, &2_u32).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #51)")]
+pub fn test_set_zone_with_invalid_geohash_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    // la "a" no es parte del alfabeto geohash
+    contract.set_zone(&admin, &1_u32, &String::from_str(&env, "Escazu"), &String::from_str(&env, "d1a0"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #52)")]
+pub fn test_remove_zone_with_cars_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.set_zone(&admin, &1_u32, &String::from_str(&env, "Escazu"), &String::from_str(&env, "d1u0"));
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_car_location(&admin, &owner, &1_u32, &String::from_str(&env, "Av. Central 123"));
+
+    contract.remove_zone(&admin, &1_u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #50)")]
+pub fn test_set_car_location_unknown_zone_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &None);
+    contract.set_car_location(&admin, &owner, &9_u32, &String::from_str(&env, "Av. Central 123"));
+}
+
+#[test]
+pub fn test_add_car_with_location_lists_car_in_zone() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let location = CarLocation { zone_id: 1, pickup_point: String::from_str(&env, "Av. Central 123") };
+
+    contract.set_zone(&admin, &1_u32, &String::from_str(&env, "Escazu"), &String::from_str(&env, "d1u0"));
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &Some(location.clone()));
+
+    assert_eq!(contract.get_car_location(&owner), location);
+    assert_eq!(contract.list_cars_in_zone(&1_u32, &0_u32, &10_u32), vec![&env, owner]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #50)")]
+pub fn test_add_car_with_unknown_zone_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let location = CarLocation { zone_id: 9, pickup_point: String::from_str(&env, "Av. Central 123") };
+
+    contract.add_car(&admin, &owner, &1500_i128, &CarCategory::Economy, &Some(location));
+}
@@ -18,6 +18,8 @@ export const CarsList = ({ cars }: CarsListProps) => {
     useStellarAccounts();
 
   const [availableByOwner, setAvailableByOwner] = useState<Record<string, number>>({});
+  const [pointsByOwner, setPointsByOwner] = useState<Record<string, number>>({});
+  const [startDateByOwner, setStartDateByOwner] = useState<Record<string, string>>({});
 
   useEffect(() => {
     const fetchAvailable = async () => {
@@ -45,7 +47,10 @@ export const CarsList = ({ cars }: CarsListProps) => {
     const contractClient =
       await stellarService.buildClient<IRentACarContract>(walletAddress);
 
-    const result = await contractClient.remove_car({ owner });
+    const result = await contractClient.remove_car({
+      caller: walletAddress,
+      owner,
+    });
     const xdr = result.toXDR();
 
     const signedTx = await walletService.signTransaction(xdr);
@@ -71,16 +76,30 @@ export const CarsList = ({ cars }: CarsListProps) => {
   const handleRent = async (
     car: ICar,
     renter: string,
-    totalDaysToRent: number
+    totalDaysToRent: number,
+    loyaltyPoints: number,
+    startDate?: string
   ) => {
     const contractClient =
       await stellarService.buildClient<IRentACarContract>(walletAddress);
 
+    const startAt = startDate
+      ? Math.floor(new Date(startDate).getTime() / 1000)
+      : Math.floor(Date.now() / 1000);
+
+    const amount = await contractClient.quote_rental({
+      owner: car.ownerAddress,
+      total_days_to_rent: totalDaysToRent,
+      start_at: startAt,
+    });
+
     const result = await contractClient.rental({
       renter,
       owner: car.ownerAddress,
       total_days_to_rent: totalDaysToRent,
-      amount: car.pricePerDay * totalDaysToRent * ONE_XLM_IN_STROOPS,
+      amount,
+      loyalty_points: loyaltyPoints,
+      start_at: startAt,
     });
     const xdr = result.toXDR();
 
@@ -147,13 +166,50 @@ export const CarsList = ({ cars }: CarsListProps) => {
       selectedRole === UserRole.RENTER &&
       car.status === CarStatus.AVAILABLE
     ) {
+      const loyaltyPoints = pointsByOwner[car.ownerAddress] ?? 0;
+      const startDate = startDateByOwner[car.ownerAddress] ?? "";
       return (
-        <button
-          onClick={() => void handleRent(car, walletAddress, 3)}
-          className="px-3 py-1 bg-blue-600 text-white rounded font-semibold hover:bg-blue-700 transition-colors cursor-pointer"
-        >
-          Rent
-        </button>
+        <div className="flex items-center gap-2">
+          <input
+            type="date"
+            value={startDate}
+            onChange={(e) =>
+              setStartDateByOwner((prev) => ({
+                ...prev,
+                [car.ownerAddress]: e.target.value,
+              }))
+            }
+            className="rounded-md border border-gray-300 p-1"
+            title="Rental start date"
+          />
+          <input
+            type="number"
+            min="0"
+            value={loyaltyPoints}
+            onChange={(e) =>
+              setPointsByOwner((prev) => ({
+                ...prev,
+                [car.ownerAddress]: Math.max(0, Number(e.target.value)),
+              }))
+            }
+            className="w-20 rounded-md border border-gray-300 p-1"
+            title="Loyalty points to redeem"
+          />
+          <button
+            onClick={() =>
+              void handleRent(
+                car,
+                walletAddress,
+                3,
+                loyaltyPoints,
+                startDate
+              )
+            }
+            className="px-3 py-1 bg-blue-600 text-white rounded font-semibold hover:bg-blue-700 transition-colors cursor-pointer"
+          >
+            Rent
+          </button>
+        </div>
       );
     }
 
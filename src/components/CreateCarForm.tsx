@@ -1,4 +1,5 @@
 import { useState } from "react";
+import { CarCategory } from "../interfaces/car-category";
 import { CreateCar } from "../interfaces/create-car";
 import Modal from "./Modal";
 
@@ -18,6 +19,7 @@ export const CreateCarForm = ({
     color: "",
     passengers: 1,
     pricePerDay: 0,
+    category: CarCategory.ECONOMY,
     ac: false,
     ownerAddress: "",
   });
@@ -35,6 +37,11 @@ export const CreateCarForm = ({
     }));
   };
 
+  const handleCategoryChange = (e: React.ChangeEvent<HTMLSelectElement>) => {
+    const category = e.target.value as CarCategory;
+    setFormData((prev) => ({ ...prev, category }));
+  };
+
   const handleSubmit = async (
     e: React.FormEvent<HTMLFormElement>
   ): Promise<void> => {
@@ -142,6 +149,28 @@ export const CreateCarForm = ({
             />
           </div>
 
+          <div>
+            <label
+              htmlFor="category"
+              className="block text-sm font-medium text-gray-700"
+            >
+              Category
+            </label>
+            <select
+              id="category"
+              name="category"
+              value={formData.category}
+              onChange={handleCategoryChange}
+              className="mt-1 block w-full rounded-md border-gray-300 shadow-sm focus:border-blue-500 focus:ring-blue-500 p-1"
+            >
+              {Object.values(CarCategory).map((category) => (
+                <option key={category} value={category}>
+                  {category}
+                </option>
+              ))}
+            </select>
+          </div>
+
           <div>
             <label
               htmlFor="ownerAddress"
@@ -0,0 +1,6 @@
+export enum CarCategory {
+  ECONOMY = "Economy",
+  SUV = "Suv",
+  LUXURY = "Luxury",
+  VAN = "Van",
+}
\ No newline at end of file
@@ -1,3 +1,4 @@
+import { CarCategory } from "./car-category";
 import { CarStatus } from "./car-status";
 
 export interface ICar {
@@ -7,6 +8,7 @@ export interface ICar {
   passengers: number;
   ac: boolean;
   pricePerDay: number;
+  category: CarCategory;
   status: CarStatus;
   ownerAddress: string;
 }
\ No newline at end of file
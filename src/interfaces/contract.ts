@@ -1,4 +1,5 @@
 import type { ClientOptions } from "@stellar/stellar-sdk/contract";
+import { CarCategory } from "./car-category";
 import { CarStatus } from "./car-status";
 
 export interface IBaseContractClient {
@@ -18,30 +19,79 @@ export interface IRentACarContract extends IBaseContractClient {
   }) => Promise<this>;
 
   add_car: ({
+    caller,
     owner,
     price_per_day,
+    category,
+    location,
   }: {
+    caller: string;
     owner: string;
     price_per_day: number;
+    category: CarCategory;
+    location?: { zone_id: number; pickup_point: string };
   }) => Promise<this>;
 
   get_car_status: ({ owner }: { owner: string }) => Promise<CarStatus>;
 
   get_available_to_withdraw: ({ owner }: { owner: string }) => Promise<number>;
 
+  get_pricing_rules: ({ owner }: { owner: string }) => Promise<{
+    weekday_multipliers_bps: number[];
+    seasonal_rates: { start: number; end: number; multiplier_bps: number }[];
+  }>;
+
+  quote_rental: ({
+    owner,
+    total_days_to_rent,
+    start_at,
+  }: {
+    owner: string;
+    total_days_to_rent: number;
+    start_at: number;
+  }) => Promise<number>;
+
   rental: ({
     renter,
     owner,
     total_days_to_rent,
     amount,
+    promo_code,
+    loyalty_points,
+    start_at,
   }: {
     renter: string;
     owner: string;
     total_days_to_rent: number;
     amount: number;
+    promo_code?: Buffer;
+    loyalty_points: number;
+    start_at: number;
   }) => Promise<this>;
 
-  remove_car: ({ owner }: { owner: string }) => Promise<this>;
+  complete_rental: ({
+    owner,
+    renter,
+  }: {
+    owner: string;
+    renter: string;
+  }) => Promise<this>;
+
+  remove_car: ({
+    caller,
+    owner,
+  }: {
+    caller: string;
+    owner: string;
+  }) => Promise<this>;
+
+  update_price: ({
+    owner,
+    price_per_day,
+  }: {
+    owner: string;
+    price_per_day: number;
+  }) => Promise<this>;
 
   payout_owner: ({
     owner,
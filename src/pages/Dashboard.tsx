@@ -22,14 +22,24 @@ export default function Dashboard() {
   const [availableCommission, setAvailableCommission] = useState<number>(0);
 
   const handleCreateCar = async (formData: CreateCar) => {
-    const { brand, model, color, passengers, pricePerDay, ac, ownerAddress } =
-      formData;
+    const {
+      brand,
+      model,
+      color,
+      passengers,
+      pricePerDay,
+      category,
+      ac,
+      ownerAddress,
+    } = formData;
     const contractClient =
       await stellarService.buildClient<IRentACarContract>(walletAddress);
 
     const addCarResult = await contractClient.add_car({
+      caller: walletAddress,
       owner: ownerAddress,
       price_per_day: pricePerDay * ONE_XLM_IN_STROOPS,
+      category,
     });
     const xdr = addCarResult.toXDR();
 
@@ -42,6 +52,7 @@ export default function Dashboard() {
       color,
       passengers,
       pricePerDay,
+      category,
       ac,
       ownerAddress,
       status: CarStatus.AVAILABLE,